@@ -0,0 +1,42 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use nmea0183::Parser;
+
+// One 10 Hz multi-constellation epoch
+const LOG: &[u8] = b"$GNRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*48\r\n\
+$GNVTG,089.0,T,,,15.2,N,,,A*0C\r\n\
+$GNGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*7C\r\n\
+$GNGSA,A,3,21,5,29,25,12,10,26,2,,,,,1.2,0.7,1.0*27\r\n\
+$GPGSV,3,1,12,01,00,000,,02,00,000,,03,00,000,,04,00,000,*7C\r\n\
+$GPGSV,3,2,12,05,00,000,,06,00,000,,07,00,000,,08,00,000,*77\r\n\
+$GPGSV,3,3,12,09,00,000,,10,00,000,,11,00,000,,12,00,000,*71\r\n\
+$GLGSV,2,1,08,65,40,083,46,66,62,110,40,72,15,270,,73,00,000,*60\r\n\
+$GLGSV,2,2,08,80,41,307,,81,09,203,,82,32,056,,88,07,334,*6E\r\n\
+$GNGLL,4916.45,N,12311.12,W,225444,A*2F\r\n";
+
+fn parse_log(c: &mut Criterion) {
+    assert!(Parser::new()
+        .parse_from_bytes(LOG)
+        .all(|result| result.is_ok()));
+    let mut group = c.benchmark_group("parser");
+    group.throughput(Throughput::Bytes(LOG.len() as u64));
+    group.bench_function("parse_from_bytes", |b| {
+        let mut parser = Parser::new();
+        b.iter(|| {
+            for result in parser.parse_from_bytes(black_box(LOG)) {
+                black_box(result.is_ok());
+            }
+        })
+    });
+    group.bench_function("raw_sentences", |b| {
+        let mut parser = Parser::new();
+        b.iter(|| {
+            for result in parser.raw_sentences(black_box(LOG)) {
+                black_box(result.is_ok());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse_log);
+criterion_main!(benches);
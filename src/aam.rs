@@ -0,0 +1,60 @@
+use crate::common;
+use crate::waypoint::WaypointId;
+use crate::Source;
+
+/// Waypoint arrival alarm.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AAM {
+    /// Navigational system.
+    pub source: Source,
+    /// Arrival circle of waypoint is entered.
+    pub arrival_circle_entered: bool,
+    /// Perpendicular to course line at waypoint is passed.
+    pub perpendicular_passed: bool,
+    /// Arrival circle radius in nautical miles.
+    pub circle_radius_nm: f32,
+    /// Waypoint identifier.
+    pub waypoint_id: Option<WaypointId>,
+}
+
+impl AAM {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let arrival_circle_entered = parse_alarm_status(fields.next())?;
+        let perpendicular_passed = parse_alarm_status(fields.next())?;
+        let circle_radius_nm = common::parse_f32(fields.next())?;
+        fields.next(); // Not needed radius nautical miles marker field
+        let waypoint_id = WaypointId::parse(fields.next())?;
+
+        if let Some(circle_radius_nm) = circle_radius_nm {
+            Ok(Some(AAM {
+                source,
+                arrival_circle_entered,
+                perpendicular_passed,
+                circle_radius_nm,
+                waypoint_id,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn parse_alarm_status(input: Option<&str>) -> Result<bool, &'static str> {
+    match input {
+        Some("A") => Ok(true),
+        Some("V") => Ok(false),
+        _ => Err("Wrong AAM status field!"),
+    }
+}
+
+#[test]
+fn test_parse_alarm_status() {
+    assert_eq!(parse_alarm_status(Some("A")), Ok(true));
+    assert_eq!(parse_alarm_status(Some("V")), Ok(false));
+    assert!(parse_alarm_status(Some("")).is_err());
+    assert!(parse_alarm_status(Some("X")).is_err());
+    assert!(parse_alarm_status(None).is_err());
+}
@@ -0,0 +1,72 @@
+use crate::common;
+use crate::coords::{Course, Latitude, Longitude, MagneticCourse};
+use crate::datetime::Time;
+use crate::modes::{Mode, Status};
+use crate::waypoint::WaypointId;
+use crate::Source;
+
+/// Bearing and distance to waypoint along great circle.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BWC {
+    /// Navigational system.
+    pub source: Source,
+    /// Time of fix in UTC.
+    pub time: Time,
+    /// Waypoint latitude.
+    pub latitude: Latitude,
+    /// Waypoint longitude.
+    pub longitude: Longitude,
+    /// Bearing to waypoint relative to true North.
+    pub bearing_true: Course,
+    /// Bearing to waypoint relative to magnetic North.
+    pub bearing_magnetic: Option<MagneticCourse>,
+    /// Distance to waypoint in nautical miles.
+    pub distance_nm: f32,
+    /// Waypoint identifier.
+    pub waypoint_id: Option<WaypointId>,
+    /// Receiver's mode of operation. Autonomous if not reported by pre NMEA 2.3 receivers.
+    pub mode: Mode,
+}
+
+impl BWC {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let time = Time::parse_from_hhmmss(fields.next())?;
+        let latitude = Latitude::parse(fields.next(), fields.next())?;
+        let longitude = Longitude::parse(fields.next(), fields.next())?;
+        let bearing_true = Course::parse(fields.next())?;
+        fields.next(); // Not needed true bearing marker field
+        let bearing_magnetic = MagneticCourse::parse_from_str(fields.next())?;
+        fields.next(); // Not needed magnetic bearing marker field
+        let distance_nm = common::parse_f32(fields.next())?;
+        fields.next(); // Not needed distance nautical miles marker field
+        let waypoint_id = WaypointId::parse(fields.next())?;
+        // Mode field is absent before NMEA 2.3, such BWC is treated as autonomous
+        let mode = Mode::from_some_str_or_status(fields.next(), &Status::Valid)?;
+
+        if let (
+            Some(time),
+            Some(latitude),
+            Some(longitude),
+            Some(bearing_true),
+            Some(distance_nm),
+        ) = (time, latitude, longitude, bearing_true, distance_nm)
+        {
+            Ok(Some(BWC {
+                source,
+                time,
+                latitude,
+                longitude,
+                bearing_true,
+                bearing_magnetic,
+                distance_nm,
+                waypoint_id,
+                mode,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
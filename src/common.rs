@@ -1,3 +1,7 @@
+use crate::parse_hex_halfbyte;
+use core::convert::TryFrom;
+use core::time::Duration;
+
 pub(crate) fn parse_u8(input: Option<&str>) -> Result<Option<u8>, &'static str> {
     match input {
         Some(s) if s.len() == 0 => Ok(None),
@@ -31,6 +35,45 @@ pub(crate) fn parse_f32(input: Option<&str>) -> Result<Option<f32>, &'static str
     }
 }
 
+pub(crate) fn parse_duration(input: Option<&str>) -> Result<Option<Duration>, &'static str> {
+    match input {
+        Some("") => Ok(None),
+        Some(s) => {
+            let seconds = s
+                .parse::<f64>()
+                .map_err(|_| "Wrong duration field format")?;
+            if seconds.is_finite() && seconds >= 0f64 {
+                Ok(Some(Duration::from_micros(
+                    (seconds * 1_000_000f64 + 0.5f64) as u64,
+                )))
+            } else {
+                Err("Duration should be finite and not negative")
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+// Hex fields are used only by some vendor specific sentences
+#[allow(dead_code)]
+pub(crate) fn parse_hex_u32(input: &str) -> Result<u32, &'static str> {
+    if input.is_empty() {
+        return Err("Wrong hex field format");
+    }
+    input.bytes().try_fold(0u32, |value, symbol| {
+        let halfbyte = parse_hex_halfbyte(symbol)?;
+        value
+            .checked_mul(16)
+            .map(|value| value | halfbyte as u32)
+            .ok_or("Hex field overflow!")
+    })
+}
+
+#[allow(dead_code)]
+pub(crate) fn parse_hex_u16(input: &str) -> Result<u16, &'static str> {
+    u16::try_from(parse_hex_u32(input)?).map_err(|_| "Hex field overflow!")
+}
+
 #[test]
 fn test_parse_u8() {
     assert_eq!(parse_u8(Some("")), Ok(None));
@@ -54,6 +97,8 @@ fn test_parse_u8() {
 fn test_parse_u16() {
     assert_eq!(parse_u16(Some("")), Ok(None));
     assert_eq!(parse_u16(Some("123")), Ok(Some(123u16)));
+    assert_eq!(parse_u16(Some("0005")), Ok(Some(5u16)));
+    assert_eq!(parse_u16(Some("0000")), Ok(Some(0u16)));
     assert_eq!(
         parse_u16(Some("a123")),
         Err("Wrong unsigned int field format")
@@ -76,3 +121,41 @@ fn test_parse_f32() {
     assert_eq!(parse_f32(Some("a123.0")), Err("Wrong float field format"));
     assert_eq!(parse_f32(None), Ok(None));
 }
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration(Some("")), Ok(None));
+    assert_eq!(parse_duration(Some("0")), Ok(Some(Duration::from_secs(0))));
+    assert_eq!(
+        parse_duration(Some("1.5")),
+        Ok(Some(Duration::from_millis(1500)))
+    );
+    assert_eq!(
+        parse_duration(Some("0.000001")),
+        Ok(Some(Duration::from_micros(1)))
+    );
+    assert_eq!(
+        parse_duration(Some("-1.5")),
+        Err("Duration should be finite and not negative")
+    );
+    assert!(parse_duration(Some("NaN")).is_err());
+    assert_eq!(
+        parse_duration(Some("a1.5")),
+        Err("Wrong duration field format")
+    );
+    assert_eq!(parse_duration(None), Ok(None));
+}
+
+#[test]
+fn test_parse_hex() {
+    assert_eq!(parse_hex_u32("FFFF"), Ok(0xFFFF));
+    assert_eq!(parse_hex_u32("00"), Ok(0));
+    assert_eq!(parse_hex_u32("deadBEEF"), Ok(0xDEADBEEF));
+    assert_eq!(parse_hex_u32("100000000"), Err("Hex field overflow!"));
+    assert_eq!(parse_hex_u32("0000000001"), Ok(1));
+    assert_eq!(parse_hex_u32("12G4"), Err("Invalid HEX character."));
+    assert_eq!(parse_hex_u32(""), Err("Wrong hex field format"));
+    assert_eq!(parse_hex_u16("FFFF"), Ok(0xFFFF));
+    assert_eq!(parse_hex_u16("00"), Ok(0));
+    assert_eq!(parse_hex_u16("10000"), Err("Hex field overflow!"));
+}
@@ -1,8 +1,9 @@
 //! Structures that describes coordinates that may be parsed from NMEA sentences.
 use core::convert::TryFrom;
+use core::fmt;
 
 /// Earth hemisphere
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Hemisphere {
     /// North
     North,
@@ -74,6 +75,12 @@ impl Latitude {
                 if lat.len() < 4 {
                     return Err("Latitude field is too short!");
                 }
+                // Layout is ddmm.mmmm, so decimal point if any goes right after minutes
+                match lat.find('.') {
+                    Some(4) => {}
+                    None if lat.len() == 4 => {}
+                    _ => return Err("Wrong latitude field format"),
+                }
                 let hemisphere = match lat_hemi {
                     "N" => Hemisphere::North,
                     "S" => Hemisphere::South,
@@ -110,6 +117,10 @@ impl Latitude {
             Hemisphere::West => panic!("Wrong West hemisphere for latitude!"),
         }
     }
+    /// Return true if latitudes differ by no more than tolerance in degrees.
+    pub fn approx_eq(&self, other: &Latitude, tolerance_degrees: f64) -> bool {
+        (self.as_f64() - other.as_f64()).abs() <= tolerance_degrees
+    }
     /// Is north hemisphere
     pub fn is_north(&self) -> bool {
         self.hemisphere == Hemisphere::North
@@ -180,6 +191,12 @@ impl Longitude {
                 if lon.len() < 5 {
                     return Err("Longitude field is too short!");
                 }
+                // Layout is dddmm.mmmm, so decimal point if any goes right after minutes
+                match lon.find('.') {
+                    Some(5) => {}
+                    None if lon.len() == 5 => {}
+                    _ => return Err("Wrong longitude field format"),
+                }
                 let hemisphere = match lon_hemi {
                     "E" => Hemisphere::East,
                     "W" => Hemisphere::West,
@@ -216,6 +233,10 @@ impl Longitude {
             Hemisphere::South => panic!("Wrong South hemisphere for latitude!"),
         }
     }
+    /// Return true if longitudes differ by no more than tolerance in degrees.
+    pub fn approx_eq(&self, other: &Longitude, tolerance_degrees: f64) -> bool {
+        (self.as_f64() - other.as_f64()).abs() <= tolerance_degrees
+    }
     /// Is in west hemisphere
     pub fn is_west(&self) -> bool {
         self.hemisphere == Hemisphere::West
@@ -264,6 +285,12 @@ impl Speed {
             knots: speed * 1.94384f32,
         }
     }
+    /// Speed from centimeters per second
+    pub fn from_cmps(speed: f32) -> Speed {
+        Speed {
+            knots: speed * 0.0194384f32,
+        }
+    }
     /// Speed from miles per hour
     pub fn from_mph(speed: f32) -> Speed {
         Speed {
@@ -278,7 +305,7 @@ impl Speed {
     }
     /// Speed as knots
     pub fn as_knots(&self) -> f32 {
-      self.knots
+        self.knots
     }
     /// Speed as kilometers per hour
     pub fn as_kph(&self) -> f32 {
@@ -292,6 +319,38 @@ impl Speed {
     pub fn as_mps(&self) -> f32 {
         self.knots * 0.514444
     }
+    /// Speed as centimeters per second
+    pub fn as_cmps(&self) -> f32 {
+        self.knots * 51.4444
+    }
+    /// Displays speed in knots
+    pub fn in_knots(&self) -> SpeedDisplay<'_> {
+        SpeedDisplay {
+            speed: self,
+            unit: SpeedUnit::Knots,
+        }
+    }
+    /// Displays speed in kilometers per hour
+    pub fn in_kph(&self) -> SpeedDisplay<'_> {
+        SpeedDisplay {
+            speed: self,
+            unit: SpeedUnit::Kph,
+        }
+    }
+    /// Displays speed in miles per hour
+    pub fn in_mph(&self) -> SpeedDisplay<'_> {
+        SpeedDisplay {
+            speed: self,
+            unit: SpeedUnit::Mph,
+        }
+    }
+    /// Displays speed in meters per second
+    pub fn in_mps(&self) -> SpeedDisplay<'_> {
+        SpeedDisplay {
+            speed: self,
+            unit: SpeedUnit::Mps,
+        }
+    }
     pub(crate) fn parse(input: Option<&str>) -> Result<Option<Self>, &'static str> {
         match input {
             Some(speed) if speed.len() == 0 => Ok(None),
@@ -304,6 +363,43 @@ impl Speed {
     }
 }
 
+/// Displays speed in knots, like `15.2 kn`.
+impl fmt::Display for Speed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.in_knots().fmt(f)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum SpeedUnit {
+    Knots,
+    Kph,
+    Mph,
+    Mps,
+}
+
+/// Displays [Speed](struct.Speed.html) in chosen unit. Respects formatter precision, like `{:.1}`.
+#[derive(Debug)]
+pub struct SpeedDisplay<'a> {
+    speed: &'a Speed,
+    unit: SpeedUnit,
+}
+
+impl fmt::Display for SpeedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (value, unit) = match self.unit {
+            SpeedUnit::Knots => (self.speed.as_knots(), "kn"),
+            SpeedUnit::Kph => (self.speed.as_kph(), "km/h"),
+            SpeedUnit::Mph => (self.speed.as_mph(), "mph"),
+            SpeedUnit::Mps => (self.speed.as_mps(), "m/s"),
+        };
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} {}", precision, value, unit),
+            None => write!(f, "{} {}", value, unit),
+        }
+    }
+}
+
 /// The course over ground.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Course {
@@ -328,6 +424,22 @@ impl Course {
             _ => Ok(None),
         }
     }
+    /// Signed shortest angle in degrees to turn from this course to other one, in range -180 to 180.
+    /// Positive value means clockwise turn.
+    pub fn shortest_diff(&self, other: &Course) -> f32 {
+        shortest_angle(self.degrees, other.degrees)
+    }
+}
+
+fn shortest_angle(from: f32, to: f32) -> f32 {
+    let diff = (to - from) % 360f32;
+    if diff > 180f32 {
+        diff - 360f32
+    } else if diff < -180f32 {
+        diff + 360f32
+    } else {
+        diff
+    }
 }
 
 /// The course over ground calculated from True course and magnetic variation.
@@ -344,6 +456,15 @@ impl From<f32> for MagneticCourse {
 }
 
 impl MagneticCourse {
+    /// Course in degrees from Magnetic North Pole rotated clockwise.
+    pub fn degrees(&self) -> f32 {
+        self.degrees
+    }
+    /// Signed shortest angle in degrees to turn from this course to other one, in range -180 to 180.
+    /// Positive value means clockwise turn.
+    pub fn shortest_diff(&self, other: &MagneticCourse) -> f32 {
+        shortest_angle(self.degrees, other.degrees)
+    }
     pub(crate) fn parse_from_str(input: Option<&str>) -> Result<Option<Self>, &'static str> {
         match input {
             Some(course) if course.len() == 0 => Ok(None),
@@ -354,6 +475,26 @@ impl MagneticCourse {
             _ => Ok(None),
         }
     }
+    pub(crate) fn parse_variation(
+        mvar: Option<&str>,
+        mdir: Option<&str>,
+    ) -> Result<(Option<f32>, Option<Hemisphere>), &'static str> {
+        let variation = match mvar {
+            Some("") | None => None,
+            Some(variation) => Some(
+                variation
+                    .parse::<f32>()
+                    .map_err(|_| "Wrong magnetic variation field format!")?,
+            ),
+        };
+        let direction = match mdir {
+            Some("E") => Some(Hemisphere::East),
+            Some("W") => Some(Hemisphere::West),
+            Some("") | None => None,
+            _ => return Err("Wrong direction field for magnetic variation"),
+        };
+        Ok((variation, direction))
+    }
     pub(crate) fn parse_from_mvar_mdir(
         true_course: &Option<Course>,
         mvar: Option<&str>,
@@ -381,3 +522,373 @@ impl MagneticCourse {
         }
     }
 }
+
+#[cfg(feature = "math")]
+/// Initial bearing (forward azimuth) on a great circle from one position to another.
+/// Returns [Course](struct.Course.html) in degrees normalized to the range 0 to 360.
+pub fn bearing_degrees(
+    from_lat: &Latitude,
+    from_lon: &Longitude,
+    to_lat: &Latitude,
+    to_lon: &Longitude,
+) -> Course {
+    let phi1 = from_lat.as_f64().to_radians();
+    let phi2 = to_lat.as_f64().to_radians();
+    let delta_lambda = (to_lon.as_f64() - from_lon.as_f64()).to_radians();
+    let y = libm::sin(delta_lambda) * libm::cos(phi2);
+    let x = libm::cos(phi1) * libm::sin(phi2)
+        - libm::sin(phi1) * libm::cos(phi2) * libm::cos(delta_lambda);
+    let degrees = libm::atan2(y, x).to_degrees();
+    Course {
+        degrees: libm::fmod(degrees + 360f64, 360f64) as f32,
+    }
+}
+
+#[cfg(feature = "math")]
+/// Speed required to move between two positions in given time, calculated from haversine distance on mean Earth sphere.
+/// Physically impossible speed indicates position jump, like caused by spoofing. None if time is not positive.
+pub fn implied_speed(
+    from_lat: &Latitude,
+    from_lon: &Longitude,
+    to_lat: &Latitude,
+    to_lon: &Longitude,
+    dt_seconds: f64,
+) -> Option<Speed> {
+    const EARTH_RADIUS: f64 = 6371008.8; // Mean Earth radius in meters
+    if dt_seconds <= 0f64 || dt_seconds.is_nan() {
+        return None;
+    }
+    let phi1 = from_lat.as_f64().to_radians();
+    let phi2 = to_lat.as_f64().to_radians();
+    let delta_phi = phi2 - phi1;
+    let delta_lambda = (to_lon.as_f64() - from_lon.as_f64()).to_radians();
+    let a = libm::pow(libm::sin(delta_phi / 2f64), 2f64)
+        + libm::cos(phi1) * libm::cos(phi2) * libm::pow(libm::sin(delta_lambda / 2f64), 2f64);
+    let distance = 2f64 * EARTH_RADIUS * libm::asin(libm::sqrt(a).min(1f64));
+    Some(Speed::from_mps((distance / dt_seconds) as f32))
+}
+
+#[cfg(feature = "math")]
+/// Universal Transverse Mercator coordinates on WGS-84 ellipsoid.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Utm {
+    /// UTM zone number from 1 to 60.
+    pub zone: u8,
+    /// North or South hemisphere.
+    pub hemisphere: Hemisphere,
+    /// Easting in meters, 500000 at central meridian of the zone.
+    pub easting: f64,
+    /// Northing in meters from equator. Equator is 10000000 in South hemisphere.
+    pub northing: f64,
+}
+
+#[cfg(feature = "math")]
+/// Converts position to [UTM](struct.Utm.html) coordinates using Krüger series of transverse Mercator projection.
+/// Zone exceptions for Norway and Svalbard are respected. UTM is defined for latitudes from 80°S to 84°N only.
+pub fn to_utm(lat: &Latitude, lon: &Longitude) -> Utm {
+    const A: f64 = 6378137f64; // WGS-84 semi-major axis
+    const F: f64 = 1f64 / 298.257223563; // WGS-84 flattening
+    const K0: f64 = 0.9996;
+    let latitude = lat.as_f64();
+    let longitude = lon.as_f64();
+    let zone = if (56f64..64f64).contains(&latitude) && (3f64..12f64).contains(&longitude) {
+        32 // Norway
+    } else if latitude >= 72f64 && (0f64..42f64).contains(&longitude) {
+        // Svalbard uses only odd zones 31 to 37
+        match longitude {
+            lon if lon < 9f64 => 31,
+            lon if lon < 21f64 => 33,
+            lon if lon < 33f64 => 35,
+            _ => 37,
+        }
+    } else {
+        (((longitude + 180f64) / 6f64) as u8 + 1).min(60)
+    };
+    let central_meridian = (zone as f64 * 6f64 - 183f64).to_radians();
+
+    let n = F / (2f64 - F);
+    let rectifying_radius = A / (1f64 + n) * (1f64 + n * n / 4f64 + n * n * n * n / 64f64);
+    let alpha = [
+        n / 2f64 - 2f64 * n * n / 3f64 + 5f64 * n * n * n / 16f64,
+        13f64 * n * n / 48f64 - 3f64 * n * n * n / 5f64,
+        61f64 * n * n * n / 240f64,
+    ];
+    let phi = latitude.to_radians();
+    let lambda = longitude.to_radians() - central_meridian;
+    let e = 2f64 * libm::sqrt(n) / (1f64 + n);
+    let t = libm::sinh(libm::atanh(libm::sin(phi)) - e * libm::atanh(e * libm::sin(phi)));
+    let xi = libm::atan2(t, libm::cos(lambda));
+    let eta = libm::atanh(libm::sin(lambda) / libm::sqrt(1f64 + t * t));
+    let (mut x, mut y) = (eta, xi);
+    for (j, alpha) in alpha.iter().enumerate() {
+        let k = 2f64 * (j + 1) as f64;
+        x += alpha * libm::cos(k * xi) * libm::sinh(k * eta);
+        y += alpha * libm::sin(k * xi) * libm::cosh(k * eta);
+    }
+    let (hemisphere, false_northing) = if latitude < 0f64 {
+        (Hemisphere::South, 10_000_000f64)
+    } else {
+        (Hemisphere::North, 0f64)
+    };
+    Utm {
+        zone,
+        hemisphere,
+        easting: 500_000f64 + K0 * rectifying_radius * x,
+        northing: false_northing + K0 * rectifying_radius * y,
+    }
+}
+
+#[cfg(feature = "math")]
+/// Maximum length of MGRS grid reference: zone, latitude band, 100 km square and 5 digits per coordinate.
+pub const MAX_MGRS_LENGTH: usize = 15;
+
+#[cfg(feature = "math")]
+/// MGRS grid reference like `31UDQ4825111943` stored in fixed buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MgrsString {
+    buffer: [u8; MAX_MGRS_LENGTH],
+    len: usize,
+}
+
+#[cfg(feature = "math")]
+impl MgrsString {
+    /// MGRS grid reference as string.
+    pub fn as_str(&self) -> &str {
+        // Constructed from ASCII only, so it is always valid UTF-8
+        core::str::from_utf8(&self.buffer[..self.len]).unwrap_or("")
+    }
+    fn push(&mut self, symbol: u8) {
+        self.buffer[self.len] = symbol;
+        self.len += 1;
+    }
+    fn push_number(&mut self, number: u32, digits: u8) {
+        for i in (0..digits).rev() {
+            self.push(b'0' + (number / 10u32.pow(i as u32) % 10) as u8);
+        }
+    }
+}
+
+#[cfg(feature = "math")]
+/// Converts position to MGRS grid reference based on [UTM](fn.to_utm.html) coordinates.
+/// Precision is number of digits per coordinate from 0 to 5, e.g. 5 for 1 m and 4 for 10 m. Greater values are treated as 5.
+pub fn to_mgrs(lat: &Latitude, lon: &Longitude, precision: u8) -> MgrsString {
+    const BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWXX";
+    const COLUMNS: [&[u8]; 3] = [b"STUVWXYZ", b"ABCDEFGH", b"JKLMNPQR"];
+    const ROWS: &[u8] = b"ABCDEFGHJKLMNPQRSTUV";
+    let precision = precision.min(5);
+    let utm = to_utm(lat, lon);
+    let band = ((lat.as_f64() + 80f64) / 8f64).max(0f64) as usize;
+    let column = (utm.easting / 100_000f64) as usize;
+    // Row letters of even zones are shifted by 5
+    let row_offset = match utm.zone % 2 {
+        0 => 5,
+        _ => 0,
+    };
+    let row = (utm.northing / 100_000f64) as usize + row_offset;
+    let mut mgrs = MgrsString {
+        buffer: [0u8; MAX_MGRS_LENGTH],
+        len: 0,
+    };
+    mgrs.push_number(utm.zone as u32, 2);
+    mgrs.push(BANDS[band.min(BANDS.len() - 1)]);
+    mgrs.push(COLUMNS[utm.zone as usize % 3][(column.max(1) - 1).min(7)]);
+    mgrs.push(ROWS[row % ROWS.len()]);
+    let divisor = 10u32.pow(5 - precision as u32);
+    mgrs.push_number(utm.easting as u32 % 100_000 / divisor, precision);
+    mgrs.push_number(utm.northing as u32 % 100_000 / divisor, precision);
+    mgrs
+}
+
+#[cfg(feature = "math")]
+#[test]
+fn test_to_mgrs() {
+    fn mgrs(lat: f64, lon: f64, precision: u8) -> MgrsString {
+        to_mgrs(
+            &TryFrom::try_from(lat).unwrap(),
+            &TryFrom::try_from(lon).unwrap(),
+            precision,
+        )
+    }
+    // Eiffel Tower
+    assert_eq!(mgrs(48.8583, 2.2945, 5).as_str(), "31UDQ4825111943");
+    assert_eq!(mgrs(48.8583, 2.2945, 4).as_str(), "31UDQ48251194");
+    assert_eq!(mgrs(48.8583, 2.2945, 0).as_str(), "31UDQ");
+    // Sydney Opera House
+    assert_eq!(mgrs(-33.8568, 151.2153, 5).as_str(), "56HLH3490052288");
+    // Moscow
+    assert_eq!(mgrs(55.7558, 37.6173, 4).as_str(), "37UDB13227976");
+    // Precision is limited to 1 m
+    assert_eq!(mgrs(55.7558, 37.6173, 9).as_str(), "37UDB1322479766");
+}
+
+#[cfg(feature = "math")]
+#[test]
+fn test_to_utm() {
+    fn utm(lat: f64, lon: f64) -> Utm {
+        to_utm(
+            &TryFrom::try_from(lat).unwrap(),
+            &TryFrom::try_from(lon).unwrap(),
+        )
+    }
+    let origin = utm(0.0, 0.0);
+    assert_eq!(origin.zone, 31);
+    assert_eq!(origin.hemisphere, Hemisphere::North);
+    assert!((origin.easting - 166021.443).abs() < 1.0);
+    assert!(origin.northing.abs() < 1.0);
+    // Eiffel Tower
+    let paris = utm(48.8583, 2.2945);
+    assert_eq!(paris.zone, 31);
+    assert!((paris.easting - 448251.898).abs() < 1.0);
+    assert!((paris.northing - 5411943.794).abs() < 1.0);
+    // Sydney Opera House
+    let sydney = utm(-33.8568, 151.2153);
+    assert_eq!(sydney.zone, 56);
+    assert_eq!(sydney.hemisphere, Hemisphere::South);
+    assert!((sydney.easting - 334900.570).abs() < 1.0);
+    assert!((sydney.northing - 6252288.753).abs() < 1.0);
+    // Moscow
+    let moscow = utm(55.7558, 37.6173);
+    assert_eq!(moscow.zone, 37);
+    assert!((moscow.easting - 413224.138).abs() < 1.0);
+    assert!((moscow.northing - 6179766.954).abs() < 1.0);
+    // Norway and Svalbard zone exceptions
+    assert_eq!(utm(60.39, 5.32).zone, 32);
+    assert_eq!(utm(78.22, 15.65).zone, 33);
+}
+
+#[cfg(feature = "math")]
+#[test]
+fn test_implied_speed() {
+    let lat: Latitude = TryFrom::try_from(0.0).unwrap();
+    let lon: Longitude = TryFrom::try_from(37.0).unwrap();
+    // 1 km to the East along equator
+    let east: Longitude = TryFrom::try_from(37.0 + 1000.0 / 6371008.8f64.to_radians()).unwrap();
+    let speed = implied_speed(&lat, &lon, &lat, &east, 1.0).unwrap();
+    assert!((speed.as_knots() - 1943.84).abs() < 1.0);
+    let speed = implied_speed(&lat, &lon, &lat, &east, 1000.0).unwrap();
+    assert!((speed.as_mps() - 1.0).abs() < 0.01);
+    assert!(implied_speed(&lat, &lon, &lat, &east, 0.0).is_none());
+    assert!(implied_speed(&lat, &lon, &lat, &east, -1.0).is_none());
+}
+
+#[cfg(feature = "math")]
+#[test]
+fn test_bearing_degrees() {
+    let lat: Latitude = TryFrom::try_from(55.0).unwrap();
+    let lon: Longitude = TryFrom::try_from(37.0).unwrap();
+    let north: Latitude = TryFrom::try_from(56.0).unwrap();
+    let east: Longitude = TryFrom::try_from(38.0).unwrap();
+    let to_north = bearing_degrees(&lat, &lon, &north, &lon);
+    assert!(to_north.degrees < 0.01 || to_north.degrees > 359.99);
+    let equator: Latitude = TryFrom::try_from(0.0).unwrap();
+    let to_east = bearing_degrees(&equator, &lon, &equator, &east);
+    assert!((to_east.degrees - 90.0).abs() < 0.01);
+    // Moscow to Saint Petersburg
+    let moscow_lat: Latitude = TryFrom::try_from(55.7558).unwrap();
+    let moscow_lon: Longitude = TryFrom::try_from(37.6173).unwrap();
+    let spb_lat: Latitude = TryFrom::try_from(59.9343).unwrap();
+    let spb_lon: Longitude = TryFrom::try_from(30.3351).unwrap();
+    let bearing = bearing_degrees(&moscow_lat, &moscow_lon, &spb_lat, &spb_lon);
+    assert!((bearing.degrees - 320.2).abs() < 1.0);
+}
+
+#[test]
+fn test_parse_variation() {
+    assert_eq!(
+        MagneticCourse::parse_variation(Some("8.7"), Some("E")),
+        Ok((Some(8.7), Some(Hemisphere::East)))
+    );
+    assert_eq!(
+        MagneticCourse::parse_variation(Some("3.1"), Some("W")),
+        Ok((Some(3.1), Some(Hemisphere::West)))
+    );
+    assert_eq!(
+        MagneticCourse::parse_variation(Some(""), Some("")),
+        Ok((None, None))
+    );
+    assert_eq!(
+        MagneticCourse::parse_variation(None, None),
+        Ok((None, None))
+    );
+    assert!(MagneticCourse::parse_variation(Some("8.7"), Some("N")).is_err());
+    assert!(MagneticCourse::parse_variation(Some("x"), Some("E")).is_err());
+}
+
+#[test]
+fn test_speed_cmps() {
+    assert!((Speed::from_cmps(100.0).as_mps() - 1.0).abs() < 0.0001);
+    assert!((Speed::from_mps(1.0).as_cmps() - 100.0).abs() < 0.01);
+    assert!((Speed::from_cmps(250.0).as_cmps() - 250.0).abs() < 0.01);
+}
+
+#[test]
+fn test_course_shortest_diff() {
+    let north_west = Course { degrees: 350.0 };
+    let north_east = Course { degrees: 10.0 };
+    assert!((north_west.shortest_diff(&north_east) - 20.0).abs() < 0.0001);
+    assert!((north_east.shortest_diff(&north_west) + 20.0).abs() < 0.0001);
+    let south = Course { degrees: 180.0 };
+    assert!((north_east.shortest_diff(&south) - 170.0).abs() < 0.0001);
+    assert!((south.shortest_diff(&north_west) - 170.0).abs() < 0.0001);
+    let magnetic_west = MagneticCourse { degrees: 270.0 };
+    let magnetic_east = MagneticCourse { degrees: 80.0 };
+    assert!((magnetic_west.shortest_diff(&magnetic_east) - 170.0).abs() < 0.0001);
+}
+
+#[test]
+fn test_parse_signed_altitude() {
+    assert_eq!(
+        Altitude::parse(Some("-123.4")),
+        Ok(Some(Altitude { meters: -123.4 }))
+    );
+    assert_eq!(
+        Altitude::parse(Some("+9.0")),
+        Ok(Some(Altitude { meters: 9.0 }))
+    );
+    assert_eq!(Altitude::parse(Some("")), Ok(None));
+    assert!(Altitude::parse(Some("--9.0")).is_err());
+}
+
+#[test]
+fn test_parse_coordinates_layout() {
+    let lon = Longitude::parse(Some("12311.12"), Some("W"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(lon.degrees, 123);
+    assert_eq!(lon.minutes, 11);
+    assert!(lon.is_west());
+    let lon = Longitude::parse(Some("03022"), Some("E")).unwrap().unwrap();
+    assert_eq!(lon.degrees, 30);
+    assert_eq!(lon.minutes, 22);
+    assert_eq!(
+        Longitude::parse(Some("9911.12"), Some("E")),
+        Err("Wrong longitude field format")
+    );
+    assert_eq!(
+        Longitude::parse(Some("123111.2"), Some("E")),
+        Err("Wrong longitude field format")
+    );
+
+    let lat = Latitude::parse(Some("4916.45"), Some("N"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(lat.degrees, 49);
+    assert_eq!(lat.minutes, 16);
+    assert_eq!(
+        Latitude::parse(Some("916.45"), Some("N")),
+        Err("Wrong latitude field format")
+    );
+}
+
+#[test]
+fn test_coordinates_approx_eq() {
+    let lat = Latitude::try_from(55.7039816).unwrap();
+    assert!(lat.approx_eq(&Latitude::try_from(55.7039820).unwrap(), 1e-6));
+    assert!(!lat.approx_eq(&Latitude::try_from(55.7040816).unwrap(), 1e-6));
+    assert!(!lat.approx_eq(&Latitude::try_from(-55.7039816).unwrap(), 1e-6));
+
+    let lon = Longitude::try_from(37.6934383).unwrap();
+    assert!(lon.approx_eq(&Longitude::try_from(37.6934379).unwrap(), 1e-6));
+    assert!(!lon.approx_eq(&Longitude::try_from(37.6935383).unwrap(), 1e-6));
+    assert!(!lon.approx_eq(&Longitude::try_from(-37.6934383).unwrap(), 1e-6));
+}
@@ -1,6 +1,6 @@
 //! NMEA date and time structures.
 /// NMEA date
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Date {
     /// NMEA day
     pub day: u8,
@@ -12,9 +12,17 @@ pub struct Date {
 
 impl Date {
     pub(crate) fn parse_from_ddmmyy(input: Option<&str>) -> Result<Option<Date>, &'static str> {
+        Date::parse_from_ddmmyy_with_pivot(input, 69)
+    }
+    /// Parses NMEA date in ddmmyy format. Two-digit years greater than pivot are 19xx, others are 20xx.
+    pub fn parse_from_ddmmyy_with_pivot(
+        input: Option<&str>,
+        pivot: u16,
+    ) -> Result<Option<Date>, &'static str> {
         match input {
             Some(date) if date.len() == 0 => Ok(None),
             Some(date) if date.len() < 6 => Err("Date input string is too short!"),
+            Some(date) if !date.is_ascii() => Err("Date input string is not ASCII!"),
             Some(date) => Ok(Some(Date {
                 day: (&date[..2])
                     .parse()
@@ -38,23 +46,48 @@ impl Date {
                     })?,
                 year: (&date[4..6])
                     .parse::<u16>()
-                    .map(|year| if year > 69 { year + 1900 } else { year + 2000 })
+                    .map(|year| {
+                        if year > pivot {
+                            year + 1900
+                        } else {
+                            year + 2000
+                        }
+                    })
                     .map_err(|_| "Year string is not a number!")?,
             })),
             _ => Ok(None),
         }
     }
+    /// Writes date in NMEA `ddmmyy` format. Year should be in range 1970-2069 to be parsed back unambiguously with default pivot.
+    /// Returns count of written bytes.
+    pub fn encode(&self, buf: &mut [u8]) -> Result<usize, &'static str> {
+        if !(1970..2070).contains(&self.year) {
+            return Err("Year is not in range 1970-2069");
+        }
+        let out = buf.get_mut(..6).ok_or("Encode buffer is too small!")?;
+        write_digits(&mut out[..2], self.day as u64);
+        write_digits(&mut out[2..4], self.month as u64);
+        write_digits(&mut out[4..], self.year as u64 % 100);
+        Ok(6)
+    }
 }
 
+/// Floating point type of seconds in [Time](struct.Time.html). It is f64 with "high-precision-time" feature and f32 otherwise.
+#[cfg(feature = "high-precision-time")]
+pub type Seconds = f64;
+/// Floating point type of seconds in [Time](struct.Time.html). It is f64 with "high-precision-time" feature and f32 otherwise.
+#[cfg(not(feature = "high-precision-time"))]
+pub type Seconds = f32;
+
 /// NMEA time in UTC
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Time {
     /// Hours as reported by receiver
     pub hours: u8,
     /// Minutes as reported by receiver
     pub minutes: u8,
     /// Seconds as reported by receiver. Precision and accuracy depends on receiver.
-    pub seconds: f32,
+    pub seconds: Seconds,
 }
 
 impl Time {
@@ -84,10 +117,10 @@ impl Time {
                         }
                     })?,
                 seconds: (&time[4..])
-                    .parse::<f32>()
+                    .parse::<Seconds>()
                     .map_err(|_| "Seconds string is not a float")
                     .and_then(|s| {
-                        if s < 60f32 {
+                        if s < 60.0 {
                             Ok(s)
                         } else {
                             Err("Seconds is not in range 0-59")
@@ -97,6 +130,66 @@ impl Time {
             _ => Ok(None),
         }
     }
+    /// Seconds elapsed since midnight.
+    pub fn as_seconds_of_day(&self) -> f64 {
+        #[cfg(feature = "high-precision-time")]
+        let seconds = self.seconds;
+        #[cfg(not(feature = "high-precision-time"))]
+        let seconds = self.seconds as f64;
+        self.hours as f64 * 3600f64 + self.minutes as f64 * 60f64 + seconds
+    }
+    /// Time from seconds elapsed since midnight.
+    /// Seconds that round up to 60 at `Seconds` precision are clamped to the largest value below 60.
+    pub fn from_seconds_of_day(seconds_of_day: f64) -> Result<Time, &'static str> {
+        if !(0f64..86400f64).contains(&seconds_of_day) {
+            return Err("Seconds of day is not in range 0-86399");
+        }
+        let hours = (seconds_of_day / 3600f64) as u8;
+        let minutes = ((seconds_of_day - hours as f64 * 3600f64) / 60f64) as u8;
+        let seconds = (seconds_of_day - hours as f64 * 3600f64 - minutes as f64 * 60f64) as Seconds;
+        let max_seconds = Seconds::from_bits((60 as Seconds).to_bits() - 1);
+        Ok(Time {
+            hours,
+            minutes,
+            seconds: seconds.min(max_seconds),
+        })
+    }
+    /// Writes time in NMEA `hhmmss.sss` format with given count of decimal places of seconds, up to 6.
+    /// Seconds are rounded, time that rounds to the next day is written as the last representable value of the day.
+    /// Returns count of written bytes.
+    pub fn encode(&self, buf: &mut [u8], decimals: u8) -> Result<usize, &'static str> {
+        if decimals > MAX_TIME_DECIMALS {
+            return Err("Too many decimal places for time!");
+        }
+        let len = if decimals == 0 {
+            6
+        } else {
+            7 + decimals as usize
+        };
+        let out = buf.get_mut(..len).ok_or("Encode buffer is too small!")?;
+        let scale = 10u64.pow(decimals as u32);
+        let scaled =
+            ((self.as_seconds_of_day() * scale as f64 + 0.5) as u64).min(86400 * scale - 1);
+        let seconds_of_day = scaled / scale;
+        write_digits(&mut out[..2], seconds_of_day / 3600);
+        write_digits(&mut out[2..4], seconds_of_day / 60 % 60);
+        write_digits(&mut out[4..6], seconds_of_day % 60);
+        if decimals > 0 {
+            out[6] = b'.';
+            write_digits(&mut out[7..], scaled % scale);
+        }
+        Ok(len)
+    }
+}
+
+const MAX_TIME_DECIMALS: u8 = 6;
+
+/// Writes value as decimal digits padded with zeroes to the whole slice.
+pub(crate) fn write_digits(out: &mut [u8], mut value: u64) {
+    for digit in out.iter_mut().rev() {
+        *digit = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
 }
 
 /// NMEA date and time in UTC
@@ -121,6 +214,31 @@ impl DateTime {
     }
 }
 
+#[test]
+fn test_encode_time() {
+    let time = Time {
+        hours: 12,
+        minutes: 55,
+        seconds: 4.049,
+    };
+    let mut buf = [0u8; 16];
+    assert_eq!(time.encode(&mut buf, 0), Ok(6));
+    assert_eq!(&buf[..6], b"125504");
+    assert_eq!(time.encode(&mut buf, 2), Ok(9));
+    assert_eq!(&buf[..9], b"125504.05");
+    assert_eq!(time.encode(&mut buf, 3), Ok(10));
+    assert_eq!(&buf[..10], b"125504.049");
+    let time = Time {
+        hours: 1,
+        minutes: 2,
+        seconds: 59.996,
+    };
+    assert_eq!(time.encode(&mut buf, 2), Ok(9));
+    assert_eq!(&buf[..9], b"010300.00");
+    assert!(time.encode(&mut buf[..8], 2).is_err());
+    assert!(time.encode(&mut buf, 7).is_err());
+}
+
 #[test]
 fn test_parse_date() {
     let date = Date::parse_from_ddmmyy(Some("010210")).unwrap().unwrap();
@@ -135,6 +253,57 @@ fn test_parse_date() {
     assert!(Date::parse_from_ddmmyy(Some("451070")).is_err());
 }
 
+#[test]
+fn test_encode_date() {
+    let mut buf = [0u8; 8];
+    let date = Date {
+        day: 20,
+        month: 9,
+        year: 2006,
+    };
+    assert_eq!(date.encode(&mut buf), Ok(6));
+    assert_eq!(&buf[..6], b"200906");
+    let date = Date {
+        day: 1,
+        month: 2,
+        year: 1995,
+    };
+    assert_eq!(date.encode(&mut buf), Ok(6));
+    assert_eq!(&buf[..6], b"010295");
+    assert_eq!(Date::parse_from_ddmmyy(Some("010295")).unwrap(), Some(date));
+    assert!(date.encode(&mut buf[..5]).is_err());
+    let date = Date {
+        day: 1,
+        month: 2,
+        year: 2070,
+    };
+    assert!(date.encode(&mut buf).is_err());
+}
+
+#[test]
+fn test_parse_date_with_pivot() {
+    let date = Date::parse_from_ddmmyy_with_pivot(Some("010250"), 50)
+        .unwrap()
+        .unwrap();
+    assert_eq!(date.year, 2050);
+    let date = Date::parse_from_ddmmyy_with_pivot(Some("010251"), 50)
+        .unwrap()
+        .unwrap();
+    assert_eq!(date.year, 1951);
+    let date = Date::parse_from_ddmmyy_with_pivot(Some("010269"), 69)
+        .unwrap()
+        .unwrap();
+    assert_eq!(date.year, 2069);
+    let date = Date::parse_from_ddmmyy_with_pivot(Some("010270"), 69)
+        .unwrap()
+        .unwrap();
+    assert_eq!(date.year, 1970);
+    assert_eq!(
+        Date::parse_from_ddmmyy_with_pivot(Some("aé2345"), 69),
+        Err("Date input string is not ASCII!")
+    );
+}
+
 #[test]
 fn test_parse_time() {
     let time = Time::parse_from_hhmmss(Some("124201.340"))
@@ -149,6 +318,36 @@ fn test_parse_time() {
     assert!(Time::parse_from_hhmmss(Some("235959.999")).is_ok());
 }
 
+#[test]
+fn test_seconds_of_day() {
+    let time = Time::parse_from_hhmmss(Some("124201.340"))
+        .unwrap()
+        .unwrap();
+    assert!((time.as_seconds_of_day() - 45721.34).abs() < 0.0001);
+    let restored = Time::from_seconds_of_day(time.as_seconds_of_day()).unwrap();
+    assert_eq!(restored.hours, 12);
+    assert_eq!(restored.minutes, 42);
+    assert!((restored.seconds - 1.34).abs() < 0.0001);
+    assert_eq!(
+        Time::from_seconds_of_day(0f64),
+        Ok(Time {
+            hours: 0,
+            minutes: 0,
+            seconds: 0.0
+        })
+    );
+    let time = Time::from_seconds_of_day(59.99999999).unwrap();
+    assert_eq!(time.hours, 0);
+    assert_eq!(time.minutes, 0);
+    assert!(time.seconds < 60.0);
+    let time = Time::from_seconds_of_day(86399.99999999).unwrap();
+    assert_eq!(time.hours, 23);
+    assert_eq!(time.minutes, 59);
+    assert!(time.seconds < 60.0);
+    assert!(Time::from_seconds_of_day(86400f64).is_err());
+    assert!(Time::from_seconds_of_day(-1f64).is_err());
+}
+
 #[test]
 fn test_from_date_and_time() {
     assert!(DateTime::from_date_and_time(
@@ -160,7 +359,7 @@ fn test_from_date_and_time() {
         Some(Time {
             hours: 1,
             minutes: 2,
-            seconds: 50.0f32
+            seconds: 50.0
         })
     )
     .is_ok());
@@ -178,9 +377,20 @@ fn test_from_date_and_time() {
         Some(Time {
             hours: 1,
             minutes: 2,
-            seconds: 50.0f32
+            seconds: 50.0
         })
     )
     .is_err());
     assert_eq!(DateTime::from_date_and_time(None, None), Ok(None));
 }
+
+#[test]
+#[cfg(feature = "high-precision-time")]
+fn test_parse_high_precision_time() {
+    let time = Time::parse_from_hhmmss(Some("125504.049123"))
+        .unwrap()
+        .unwrap();
+    assert!((time.seconds - 4.049123f64).abs() < 1e-12);
+    let low_precision = "04.049123".parse::<f32>().unwrap();
+    assert!((low_precision as f64 - 4.049123f64).abs() > 1e-9);
+}
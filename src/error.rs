@@ -0,0 +1,117 @@
+/// Stable numeric code of error message returned by parser. Allows to handle errors without string matching.
+/// Codes are grouped by ranges: 1-99 framing errors, 100-199 sentence identification errors,
+/// 200-299 generic field errors, 300-399 coordinates, 400-499 date and time, 500-599 modes and statuses,
+/// 600-699 sentence specific errors. Returns 0 for unknown message.
+pub fn error_code(msg: &str) -> u16 {
+    match msg {
+        "NMEA format error!" => 1,
+        "Checksum error!" => 2,
+        "Invalid HEX character." => 3,
+        "NMEA sentence is too long!" => 4,
+        "Not an ascii!" => 5,
+        "TAG block is too long!" => 6,
+        "TAG block checksum not found!" => 7,
+        "TAG block checksum error!" => 8,
+        "TAG block source is too long!" => 9,
+        "Wrong TAG block timestamp format" => 10,
+        "Wrong TAG block group format" => 11,
+
+        "Unsupported sentence type." => 100,
+        "Source is not supported!" => 101,
+        "Source field is too small!" => 102,
+        "Sentence type not found but mandatory!" => 103,
+        "Sentence field is too small. Must be 5 chars at least!" => 104,
+        "PMTK Sentence field is too small. Must be 7 chars at least!" => 105,
+        "PGRM Sentence field is too small. Must be 5 chars at least!" => 106,
+        "Sentence subtype is not available in fields!" => 107,
+        "Unsupported MTKPacketType." => 108,
+        "Unsupported GarminSentenceType." => 109,
+        "Unsupported PUBXMessageType." => 110,
+        "PUBX message type is mandatory!" => 111,
+
+        "Wrong unsigned int field format" => 200,
+        "Wrong float field format" => 201,
+        "Wrong duration field format" => 202,
+        "Duration should be finite and not negative" => 203,
+        "Encode buffer is too small!" => 204,
+        "Wrong hex field format" => 205,
+        "Hex field overflow!" => 206,
+
+        "Latitude field is too short!" => 300,
+        "Wrong latitude field format" => 301,
+        "Latitude hemisphere field has wrong format!" => 302,
+        "Could not parse latitude from hemisphere only" => 303,
+        "Could not parse latitude from coordinate only" => 304,
+        "Latitude is not in range -90 to 90 degrees!" => 305,
+        "Longitude field is too short!" => 310,
+        "Wrong longitude field format" => 311,
+        "Longitude hemisphere field has wrong format!" => 312,
+        "Could not parse longitude from hemisphere only" => 313,
+        "Could not parse longitude from coordinate only" => 314,
+        "Latitude is not in range -180 to 180 degrees!" => 315,
+        "Wrong altitude field format" => 320,
+        "Wrong speed field format" => 321,
+        "Wrong course field format" => 322,
+        "Wrong magnetic variation field format!" => 323,
+        "Wrong direction field for magnetic variation" => 324,
+
+        "Date input string is too short!" => 400,
+        "Day string is not a number!" => 401,
+        "Day is not in range 1-31" => 402,
+        "Month string is not a number!" => 403,
+        "Months is not in range 1-12" => 404,
+        "Year string is not a number!" => 405,
+        "Hours string is not a number!" => 406,
+        "Hours is not in range 0-23" => 407,
+        "Minutes string is not a number!" => 408,
+        "Minutes is not in range 0-59" => 409,
+        "Seconds string is not a float" => 410,
+        "Seconds is not in range 0-59" => 411,
+        "Seconds of day is not in range 0-86399" => 412,
+        "Date or time is None, should be Some both" => 413,
+        "Too many decimal places for time!" => 414,
+        "Year is not in range 1970-2069" => 415,
+        "Date input string is not ASCII!" => 416,
+
+        "Wrong mode character!" => 500,
+        "Mode should not be empty string!" => 501,
+        "Mode field shoud not be null!" => 502,
+        "Invalid status field!" => 503,
+        "Wrong navigational status character!" => 504,
+        "Wrong GPSQuality indicator type!" => 505,
+        "Wrong FixType indicator type!" => 506,
+        "Wrong JammingStatus indicator type!" => 507,
+        "Wrong PUBXNavStatus indicator type!" => 508,
+        "Status field is mandatory for RMC sentence!" => 509,
+        "Status field is mandatory for GLL sentence!" => 510,
+        "Status field is mandatory for VBW sentence!" => 511,
+        "Status field is mandatory for RMA sentence!" => 512,
+
+        "Too many satellites in GSV message!" => 600,
+        "Satellite elevation should not exceed 90 degrees!" => 601,
+        "Satellite azimuth should be less than 360 degrees!" => 602,
+        "Malformed GSA field count!" => 603,
+        "Wrong MSK selection mode!" => 604,
+        "Wrong AAM status field!" => 605,
+        "Waypoint ID is too long!" => 606,
+        "Wrong RTE route type!" => 607,
+        "Too many waypoints in RTE sentence!" => 608,
+        "RTE waypoints are too long!" => 609,
+        "Compact fix course is out of range!" => 610,
+        "Wrong OSD heading status!" => 611,
+        "Wrong OSD reference field!" => 612,
+        "Wrong OSD speed units field!" => 613,
+        "GGA altitude is reported for 2D fix!" => 614,
+        "GGA coordinates are reported without fix!" => 615,
+        "GSA reports more satellites than GGA!" => 616,
+        _ => 0,
+    }
+}
+
+#[test]
+fn test_error_code() {
+    assert_eq!(error_code("Checksum error!"), 2);
+    assert_eq!(error_code("NMEA format error!"), 1);
+    assert_eq!(error_code("Unsupported sentence type."), 100);
+    assert_eq!(error_code("Some unknown error"), 0);
+}
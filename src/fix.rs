@@ -0,0 +1,338 @@
+use crate::coords::{Altitude, Course, Latitude, Longitude, Speed};
+use crate::datetime::{Date, Time};
+use crate::gga::{GPSQuality, GGA};
+use crate::gsa::{FixType, GSA};
+use crate::ParseResult;
+use core::convert::TryFrom;
+
+/// Size of compact fix representation in bytes.
+pub const COMPACT_FIX_SIZE: usize = 20;
+const COMPACT_NONE_U16: u16 = u16::MAX;
+const COMPACT_NONE_U8: u8 = u8::MAX;
+const COMPACT_YEAR_EPOCH: u16 = 1900;
+
+/// Snapshot of receiver's solution merged from RMC, GGA and GSA sentences of the same epoch.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Fix {
+    /// Time of fix in UTC.
+    pub time: Time,
+    /// Date of fix in UTC. Reported by RMC only.
+    pub date: Option<Date>,
+    /// Latitude in reference datum, typically WGS-84.
+    pub latitude: Latitude,
+    /// Logitude in reference datum, typically WGS-84.
+    pub longitude: Longitude,
+    /// Altitude over ground. Reported by GGA only.
+    pub altitude: Option<Altitude>,
+    /// Speed over ground. Reported by RMC only.
+    pub speed: Option<Speed>,
+    /// Course over ground. Reported by RMC only.
+    pub course: Option<Course>,
+    /// Quality of GPS solution. Reported by GGA only.
+    pub gps_quality: Option<GPSQuality>,
+    /// Sattelites in use. Reported by GGA only.
+    pub sat_in_use: Option<u8>,
+    /// Fix type. Reported by GSA only.
+    pub fix_type: Option<FixType>,
+    /// Position dilusion of precision. Reported by GSA only.
+    pub pdop: Option<f32>,
+    /// Horizontal dilusion of precision. Reported by GGA and GSA.
+    pub hdop: Option<f32>,
+    /// Vertical dilusion of precision. Reported by GSA only.
+    pub vdop: Option<f32>,
+}
+
+impl Fix {
+    /// Encodes essential fix data into compact little endian representation for bandwidth limited links.
+    ///
+    /// | Bytes  | Type | Field                                                      |
+    /// |--------|------|------------------------------------------------------------|
+    /// | 0..4   | i32  | Latitude in 1e-7 degrees                                   |
+    /// | 4..8   | i32  | Longitude in 1e-7 degrees                                  |
+    /// | 8..10  | u16  | Speed in cm/s, 0xFFFF if not available                     |
+    /// | 10..12 | u16  | Course in 0.01 degrees, 0xFFFF if not available            |
+    /// | 12..16 | u32  | Time of fix in milliseconds since midnight                 |
+    /// | 16     | u8   | Day of month, 0 if date is not available                   |
+    /// | 17     | u8   | Month                                                      |
+    /// | 18     | u8   | Years since 1900                                           |
+    /// | 19     | u8   | [GPSQuality](enum.GPSQuality.html), 0xFF if not available  |
+    ///
+    /// Altitude, satellites count, fix type and DOPs are not encoded.
+    /// Date with year outside of 1900-2155 range is encoded as not available.
+    /// Vendor specific GPS quality code 255 is decoded as not available.
+    pub fn to_compact(&self) -> [u8; COMPACT_FIX_SIZE] {
+        let mut compact = [0u8; COMPACT_FIX_SIZE];
+        let latitude = round(self.latitude.as_f64() * 1e7) as i32;
+        let longitude = round(self.longitude.as_f64() * 1e7) as i32;
+        let speed = self.speed.as_ref().map_or(COMPACT_NONE_U16, |s| {
+            round(s.as_cmps() as f64).min(65534f64) as u16
+        });
+        let course = self.course.as_ref().map_or(COMPACT_NONE_U16, |c| {
+            round(c.degrees as f64 * 100f64) as u16 % 36000
+        });
+        let time = (round(self.time.as_seconds_of_day() * 1000f64) as u32).min(86_399_999);
+        compact[0..4].copy_from_slice(&latitude.to_le_bytes());
+        compact[4..8].copy_from_slice(&longitude.to_le_bytes());
+        compact[8..10].copy_from_slice(&speed.to_le_bytes());
+        compact[10..12].copy_from_slice(&course.to_le_bytes());
+        compact[12..16].copy_from_slice(&time.to_le_bytes());
+        if let Some(date) = &self.date {
+            if let Some(years) = date
+                .year
+                .checked_sub(COMPACT_YEAR_EPOCH)
+                .filter(|years| *years <= u8::MAX as u16)
+            {
+                compact[16] = date.day;
+                compact[17] = date.month;
+                compact[18] = years as u8;
+            }
+        }
+        compact[19] = self
+            .gps_quality
+            .as_ref()
+            .map_or(COMPACT_NONE_U8, GPSQuality::as_u8);
+        compact
+    }
+    /// Decodes fix from representation made by [to_compact](#method.to_compact).
+    /// Fields that are not encoded are set to None.
+    pub fn from_compact(compact: &[u8; COMPACT_FIX_SIZE]) -> Result<Fix, &'static str> {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(&compact[0..4]);
+        let latitude = Latitude::try_from(i32::from_le_bytes(buf) as f64 / 1e7)?;
+        buf.copy_from_slice(&compact[4..8]);
+        let longitude = Longitude::try_from(i32::from_le_bytes(buf) as f64 / 1e7)?;
+        let speed = match u16::from_le_bytes([compact[8], compact[9]]) {
+            COMPACT_NONE_U16 => None,
+            speed => Some(Speed::from_cmps(speed as f32)),
+        };
+        let course = match u16::from_le_bytes([compact[10], compact[11]]) {
+            COMPACT_NONE_U16 => None,
+            course if course < 36000 => Some(Course::from(course as f32 / 100f32)),
+            _ => return Err("Compact fix course is out of range!"),
+        };
+        buf.copy_from_slice(&compact[12..16]);
+        let time = Time::from_seconds_of_day(u32::from_le_bytes(buf) as f64 / 1000f64)?;
+        let date = match (compact[16], compact[17]) {
+            (0, _) => None,
+            (32..=u8::MAX, _) => return Err("Day is not in range 1-31"),
+            (_, 0) | (_, 13..=u8::MAX) => return Err("Months is not in range 1-12"),
+            (day, month) => Some(Date {
+                day,
+                month,
+                year: COMPACT_YEAR_EPOCH + compact[18] as u16,
+            }),
+        };
+        let gps_quality = match compact[19] {
+            COMPACT_NONE_U8 => None,
+            quality => Some(GPSQuality::from(quality)),
+        };
+        Ok(Fix {
+            time,
+            date,
+            latitude,
+            longitude,
+            altitude: None,
+            speed,
+            course,
+            gps_quality,
+            sat_in_use: None,
+            fix_type: None,
+            pdop: None,
+            hdop: None,
+            vdop: None,
+        })
+    }
+}
+
+fn round(value: f64) -> f64 {
+    // f64::round is not available in core
+    if value < 0f64 {
+        (value - 0.5f64) as i64 as f64
+    } else {
+        (value + 0.5f64) as i64 as f64
+    }
+}
+
+/// Merges RMC, GGA and GSA sentences into the [Fix](struct.Fix.html).
+/// Sentences are correlated by time of fix, GSA has no time so it updates the latest fix.
+/// GSA data is carried to the next fix unless gap between fixes exceeds the configured one.
+pub struct FixAggregator {
+    fix: Option<Fix>,
+    max_gap: f32,
+}
+
+impl FixAggregator {
+    /// Constructs new FixAggregator. Data older than max_gap seconds is not carried to the new fix.
+    pub fn new(max_gap: f32) -> FixAggregator {
+        FixAggregator { fix: None, max_gap }
+    }
+    /// Updates current fix from parsed sentence. Unrelated sentences and sentences without valid data are ignored.
+    pub fn update(&mut self, result: &ParseResult) {
+        match result {
+            ParseResult::RMC(Some(rmc)) => {
+                let fix = self.epoch(&rmc.datetime.time, &rmc.latitude, &rmc.longitude);
+                fix.date = Some(rmc.datetime.date);
+                fix.speed = Some(rmc.speed.clone());
+                fix.course = rmc.course.clone();
+            }
+            ParseResult::GGA(Some(gga)) => {
+                let fix = self.epoch(&gga.time, &gga.latitude, &gga.longitude);
+                fix.altitude = gga.altitude.clone();
+                fix.gps_quality = Some(gga.gps_quality);
+                fix.sat_in_use = Some(gga.sat_in_use);
+                fix.hdop = Some(gga.hdop);
+            }
+            ParseResult::GSA(Some(gsa)) => {
+                if let Some(fix) = self.fix.as_mut() {
+                    fix.fix_type = Some(gsa.fix_type);
+                    fix.pdop = Some(gsa.pdop);
+                    fix.hdop = Some(gsa.hdop);
+                    fix.vdop = Some(gsa.vdop);
+                }
+            }
+            _ => {}
+        }
+    }
+    /// Current merged fix if any.
+    pub fn current(&self) -> Option<&Fix> {
+        self.fix.as_ref()
+    }
+
+    fn epoch(&mut self, time: &Time, latitude: &Latitude, longitude: &Longitude) -> &mut Fix {
+        let fix = match self.fix.take() {
+            Some(fix) if fix.time == *time => fix,
+            previous => {
+                let max_gap = self.max_gap;
+                let carried = previous.filter(|fix| {
+                    let mut gap = time.as_seconds_of_day() - fix.time.as_seconds_of_day();
+                    if gap < 0f64 {
+                        gap += 86400f64; // Midnight rollover
+                    }
+                    gap <= max_gap as f64
+                });
+                Fix {
+                    time: *time,
+                    date: None,
+                    latitude: latitude.clone(),
+                    longitude: longitude.clone(),
+                    altitude: None,
+                    speed: None,
+                    course: None,
+                    gps_quality: None,
+                    sat_in_use: None,
+                    fix_type: carried.as_ref().and_then(|c| c.fix_type),
+                    pdop: carried.as_ref().and_then(|c| c.pdop),
+                    hdop: carried.as_ref().and_then(|c| c.hdop),
+                    vdop: carried.as_ref().and_then(|c| c.vdop),
+                }
+            }
+        };
+        let fix = self.fix.get_or_insert(fix);
+        fix.latitude = latitude.clone();
+        fix.longitude = longitude.clone();
+        fix
+    }
+}
+
+/// Collects statistics of fix update rate and gaps between fixes without heap allocation.
+/// Epochs are identified by time of fix reported in RMC, GGA and GLL sentences.
+#[derive(Debug, Default)]
+pub struct FixStats {
+    last_time: Option<Time>,
+    last_gap: Option<f64>,
+    min_gap: Option<f64>,
+    max_gap: Option<f64>,
+    gaps_sum: f64,
+    gaps_count: u32,
+    epochs: u32,
+    no_fix_epochs: u32,
+}
+
+impl FixStats {
+    /// Constructs new empty FixStats.
+    pub fn new() -> FixStats {
+        Default::default()
+    }
+    /// Updates statistics from parsed sentence. Sentences without time of fix are ignored.
+    pub fn update(&mut self, result: &ParseResult) {
+        let time = match result {
+            ParseResult::RMC(Some(rmc)) => &rmc.datetime.time,
+            ParseResult::GGA(Some(gga)) => &gga.time,
+            ParseResult::GLL(Some(gll)) => &gll.time,
+            _ => return,
+        };
+        self.update_epoch(time, result.has_valid_fix());
+    }
+    /// Updates statistics with time of fix. Repeated time is treated as the same epoch.
+    pub fn update_epoch(&mut self, time: &Time, valid_fix: bool) {
+        if self.last_time.as_ref() == Some(time) {
+            return;
+        }
+        if let Some(last_time) = &self.last_time {
+            let mut gap = time.as_seconds_of_day() - last_time.as_seconds_of_day();
+            if gap < 0f64 {
+                gap += 86400f64; // Midnight rollover
+            }
+            self.last_gap = Some(gap);
+            self.min_gap = Some(self.min_gap.map_or(gap, |min| min.min(gap)));
+            self.max_gap = Some(self.max_gap.map_or(gap, |max| max.max(gap)));
+            self.gaps_sum += gap;
+            self.gaps_count += 1;
+        }
+        self.last_time = Some(*time);
+        self.epochs += 1;
+        if !valid_fix {
+            self.no_fix_epochs += 1;
+        }
+    }
+    /// Average fix update rate in Hz.
+    pub fn rate_hz(&self) -> Option<f64> {
+        self.average_gap_seconds().map(|avg| 1f64 / avg)
+    }
+    /// Interval between two latest epochs in seconds.
+    pub fn last_gap_seconds(&self) -> Option<f64> {
+        self.last_gap
+    }
+    /// Minimal interval between epochs in seconds.
+    pub fn min_gap_seconds(&self) -> Option<f64> {
+        self.min_gap
+    }
+    /// Maximal interval between epochs in seconds.
+    pub fn max_gap_seconds(&self) -> Option<f64> {
+        self.max_gap
+    }
+    /// Average interval between epochs in seconds.
+    pub fn average_gap_seconds(&self) -> Option<f64> {
+        if self.gaps_count > 0 && self.gaps_sum > 0f64 {
+            Some(self.gaps_sum / self.gaps_count as f64)
+        } else {
+            None
+        }
+    }
+    /// Count of epochs seen.
+    pub fn epochs(&self) -> u32 {
+        self.epochs
+    }
+    /// Count of epochs without valid fix.
+    pub fn no_fix_epochs(&self) -> u32 {
+        self.no_fix_epochs
+    }
+}
+
+/// Checks that GGA and GSA of the same epoch do not contradict each other.
+/// Returns error describing the first found inconsistency: altitude reported for 2D fix,
+/// coordinates reported without fix or GSA listing more satellites than GGA uses.
+pub fn cross_check_fix(gga: &GGA, gsa: &GSA) -> Result<(), &'static str> {
+    if gsa.fix_type == FixType::Fix2D && matches!(gga.altitude, Some(ref a) if a.meters != 0f32) {
+        return Err("GGA altitude is reported for 2D fix!");
+    }
+    if gga.gps_quality == GPSQuality::NoFix
+        && (gga.latitude.as_f64() != 0f64 || gga.longitude.as_f64() != 0f64)
+    {
+        return Err("GGA coordinates are reported without fix!");
+    }
+    if gsa.get_fix_satellites_prn().len() > gga.sat_in_use as usize {
+        return Err("GSA reports more satellites than GGA!");
+    }
+    Ok(())
+}
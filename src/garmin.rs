@@ -0,0 +1,116 @@
+use crate::common;
+use crate::gsa::FixType;
+use crate::quality::Accuracy;
+use crate::Source;
+use core::convert::TryFrom;
+
+/// Garmin proprietary sentence type
+#[derive(Debug, PartialEq, Clone)]
+pub enum GarminSentenceType {
+    /// Estimated position error
+    E,
+    /// Altitude
+    Z,
+}
+
+impl TryFrom<&str> for GarminSentenceType {
+    type Error = &'static str;
+
+    fn try_from(from: &str) -> Result<Self, Self::Error> {
+        match from {
+            "E" => Ok(GarminSentenceType::E),
+            "Z" => Ok(GarminSentenceType::Z),
+            _ => Err("Unsupported GarminSentenceType."),
+        }
+    }
+}
+
+/// Estimated position error reported by Garmin receivers.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PGRME {
+    /// Navigational system.
+    pub source: Source,
+    /// Estimated horizontal position error in meters.
+    pub horizontal_error: f32,
+    /// Estimated vertical position error in meters.
+    pub vertical_error: f32,
+    /// Estimated overall spherical equivalent position error in meters.
+    pub spherical_error: f32,
+}
+
+impl PGRME {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let horizontal_error = common::parse_f32(fields.next())?;
+        fields.next(); // Skip horizontal error units (always meters)
+        let vertical_error = common::parse_f32(fields.next())?;
+        fields.next(); // Skip vertical error units (always meters)
+        let spherical_error = common::parse_f32(fields.next())?;
+        if let (Some(horizontal_error), Some(vertical_error), Some(spherical_error)) =
+            (horizontal_error, vertical_error, spherical_error)
+        {
+            Ok(Some(PGRME {
+                source,
+                horizontal_error,
+                vertical_error,
+                spherical_error,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Estimated position errors as unified accuracy.
+    pub fn accuracy(&self) -> Accuracy {
+        Accuracy {
+            horizontal: Some(self.horizontal_error),
+            vertical: Some(self.vertical_error),
+            position: Some(self.spherical_error),
+        }
+    }
+}
+
+/// Altitude reported by Garmin receivers.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PGRMZ {
+    /// Navigational system.
+    pub source: Source,
+    /// Altitude in feet.
+    pub altitude_feet: f32,
+    /// Position fix dimension altitude comes from.
+    pub position_fix_dimension: FixType,
+}
+
+impl PGRMZ {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let altitude_feet = common::parse_f32(fields.next())?;
+        fields.next(); // Skip altitude units (always feet)
+        let position_fix_dimension = FixType::parse(fields.next())?;
+        if let (Some(altitude_feet), Some(position_fix_dimension)) =
+            (altitude_feet, position_fix_dimension)
+        {
+            Ok(Some(PGRMZ {
+                source,
+                altitude_feet,
+                position_fix_dimension,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Altitude in meters.
+    pub fn as_meters(&self) -> f32 {
+        self.altitude_feet * 0.3048
+    }
+}
+
+#[test]
+fn test_parse_garmin_sentence_type() {
+    assert_eq!(GarminSentenceType::try_from("E"), Ok(GarminSentenceType::E));
+    assert_eq!(GarminSentenceType::try_from("Z"), Ok(GarminSentenceType::Z));
+    assert!(GarminSentenceType::try_from("X").is_err());
+}
@@ -1,7 +1,10 @@
 use crate::common;
 use crate::coords::{Altitude, Latitude, Longitude};
 use crate::datetime::Time;
+use crate::gsa::FixType;
+use crate::quality::Dop;
 use crate::Source;
+use core::convert::TryFrom;
 use core::time::Duration;
 
 /// Geographic coordinates including altitude, GPS solution quality, DGPS usage information.
@@ -25,9 +28,11 @@ pub struct GGA {
     pub altitude: Option<Altitude>,
     /// The difference between reference ellipsoid surface and mean-sea-level.
     pub geoidal_separation: Option<f32>,
-    /// DGPS data age. None if DGPS not in use.
+    /// DGPS data age. None if the field is empty as DGPS is not in use.
+    /// Zero age reported as `0` is kept as `Some` zero duration, so it is distinguishable from DGPS not in use.
     pub age_dgps: Option<Duration>,
-    /// ID of reference DGPS station used for fix. None if DGPS not in use.
+    /// ID of reference DGPS station used for fix in range 0000-1023, leading zeros are allowed.
+    /// Station ID 0 is valid and reported as `Some(0)`. None if the field is empty as DGPS is not in use.
     pub dgps_station_id: Option<u16>,
 }
 
@@ -46,8 +51,7 @@ impl GGA {
         fields.next(); // Skip altitude type (always meters according to NMEA spec)
         let geoidal_separation = common::parse_f32(fields.next())?;
         fields.next(); // Skip geoidal separation type (always meters according to NMEA spec)
-        let age_dgps = common::parse_f32(fields.next())?
-            .and_then(|a| Some(Duration::from_millis((a * 1000f32) as u64)));
+        let age_dgps = common::parse_duration(fields.next())?;
         let dgps_station_id = common::parse_u16(fields.next())?;
         if let (
             Some(time),
@@ -75,10 +79,41 @@ impl GGA {
             Ok(None)
         }
     }
+    /// Dilusion of precision. Only horizontal one is reported by GGA.
+    pub fn dop(&self) -> Dop {
+        Dop {
+            pdop: None,
+            hdop: self.hdop,
+            vdop: None,
+        }
+    }
+    /// Altitude over mean sea level in meters.
+    pub fn altitude_msl(&self) -> Option<f32> {
+        self.altitude.as_ref().map(|a| a.meters)
+    }
+    /// Altitude over reference ellipsoid in meters. Requires both altitude and geoidal separation.
+    pub fn altitude_ellipsoidal(&self) -> Option<f32> {
+        match (&self.altitude, self.geoidal_separation) {
+            (Some(altitude), Some(separation)) => Some(altitude.meters + separation),
+            _ => None,
+        }
+    }
+    /// Fix type guessed from GGA data alone as GGA lacks fix type reported by GSA.
+    /// Fix is considered 3D if altitude is reported, at least 4 satellites are in use and HDOP is not above 20.
+    /// Otherwise valid solution is considered 2D.
+    pub fn approximate_fix_type(&self) -> FixType {
+        if self.gps_quality == GPSQuality::NoFix {
+            FixType::NoFix
+        } else if self.altitude.is_some() && self.sat_in_use >= 4 && self.hdop <= 20f32 {
+            FixType::Fix3D
+        } else {
+            FixType::Fix2D
+        }
+    }
 }
 
 /// Quality of GPS solution
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum GPSQuality {
     /// No solution
     NoFix,
@@ -98,25 +133,65 @@ pub enum GPSQuality {
     Manual,
     /// Simulated.
     Simulated,
+    /// Vendor specific code not defined by NMEA, like 9 used by some receivers for manual input.
+    /// Parser produces it for codes above 8 only, use `From<u8>` to get normalized value from any code.
+    Other(u8),
 }
 
 impl GPSQuality {
     pub(crate) fn parse(input: Option<&str>) -> Result<Option<GPSQuality>, &'static str> {
         match input {
-            Some("0") => Ok(Some(GPSQuality::NoFix)),
-            Some("1") => Ok(Some(GPSQuality::GPS)),
-            Some("2") => Ok(Some(GPSQuality::DGPS)),
-            Some("3") => Ok(Some(GPSQuality::PPS)),
-            Some("4") => Ok(Some(GPSQuality::RTK)),
-            Some("5") => Ok(Some(GPSQuality::FRTK)),
-            Some("6") => Ok(Some(GPSQuality::Estimated)),
-            Some("7") => Ok(Some(GPSQuality::Manual)),
-            Some("8") => Ok(Some(GPSQuality::Simulated)),
             Some("") => Ok(None),
+            Some(code) => GPSQuality::try_from(code).map(Some),
             None => Ok(None),
-            _ => Err("Wrong GPSQuality indicator type!"),
         }
     }
+    /// NMEA numeric code of solution quality.
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            GPSQuality::NoFix => 0,
+            GPSQuality::GPS => 1,
+            GPSQuality::DGPS => 2,
+            GPSQuality::PPS => 3,
+            GPSQuality::RTK => 4,
+            GPSQuality::FRTK => 5,
+            GPSQuality::Estimated => 6,
+            GPSQuality::Manual => 7,
+            GPSQuality::Simulated => 8,
+            GPSQuality::Other(code) => *code,
+        }
+    }
+}
+
+/// Codes 0-8 are converted to named variants, any other code to [Other](#variant.Other).
+impl From<u8> for GPSQuality {
+    fn from(from: u8) -> Self {
+        match from {
+            0 => GPSQuality::NoFix,
+            1 => GPSQuality::GPS,
+            2 => GPSQuality::DGPS,
+            3 => GPSQuality::PPS,
+            4 => GPSQuality::RTK,
+            5 => GPSQuality::FRTK,
+            6 => GPSQuality::Estimated,
+            7 => GPSQuality::Manual,
+            8 => GPSQuality::Simulated,
+            _ => GPSQuality::Other(from),
+        }
+    }
+}
+
+impl TryFrom<&str> for GPSQuality {
+    type Error = &'static str;
+
+    fn try_from(from: &str) -> Result<Self, Self::Error> {
+        if from.is_empty() || !from.bytes().all(|b| b.is_ascii_digit()) {
+            return Err("Wrong GPSQuality indicator type!");
+        }
+        from.parse::<u8>()
+            .map(GPSQuality::from)
+            .map_err(|_| "Wrong GPSQuality indicator type!")
+    }
 }
 
 #[test]
@@ -138,5 +213,39 @@ fn test_parse_gpsquality() {
     );
     assert_eq!(GPSQuality::parse(Some("")), Ok(None));
     assert_eq!(GPSQuality::parse(None), Ok(None));
-    assert!(GPSQuality::parse(Some("9")).is_err());
+    assert_eq!(GPSQuality::parse(Some("9")), Ok(Some(GPSQuality::Other(9))));
+    assert_eq!(
+        GPSQuality::parse(Some("10")),
+        Ok(Some(GPSQuality::Other(10)))
+    );
+    assert_eq!(GPSQuality::from(2), GPSQuality::DGPS);
+    assert_eq!(GPSQuality::from(255), GPSQuality::Other(255));
+    assert!(GPSQuality::parse(Some("A")).is_err());
+    assert!(GPSQuality::parse(Some("256")).is_err());
+    assert!(GPSQuality::parse(Some("+1")).is_err());
+}
+
+#[test]
+fn test_gpsquality_code_roundtrip() {
+    let all = [
+        GPSQuality::NoFix,
+        GPSQuality::GPS,
+        GPSQuality::DGPS,
+        GPSQuality::PPS,
+        GPSQuality::RTK,
+        GPSQuality::FRTK,
+        GPSQuality::Estimated,
+        GPSQuality::Manual,
+        GPSQuality::Simulated,
+    ];
+    for (code, quality) in all.iter().enumerate() {
+        assert_eq!(quality.as_u8(), code as u8);
+        assert_eq!(GPSQuality::from(quality.as_u8()), quality.clone());
+    }
+    assert_eq!(GPSQuality::try_from("2"), Ok(GPSQuality::DGPS));
+    assert_eq!(GPSQuality::from(9u8), GPSQuality::Other(9));
+    assert_eq!(GPSQuality::Other(9).as_u8(), 9);
+    assert_eq!(GPSQuality::from(10u8), GPSQuality::Other(10));
+    assert!(GPSQuality::try_from("").is_err());
+    assert_eq!(GPSQuality::try_from("12"), Ok(GPSQuality::Other(12)));
 }
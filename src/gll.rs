@@ -26,12 +26,16 @@ impl GLL {
         let latitude = Latitude::parse(fields.next(), fields.next())?;
         let longitude = Longitude::parse(fields.next(), fields.next())?;
         let time = Time::parse_from_hhmmss(fields.next())?;
-        let status = if let Some(f_status) = fields.next() {
-            Status::from_str(f_status)?
-        } else {
-            return Err("Status field is mandatory for GLL sentence!");
+        let mode = match fields.next() {
+            Some(f_status) => {
+                let status = Status::from_str(f_status)?;
+                Mode::from_some_str_or_status(fields.next(), &status)?
+            }
+            #[cfg(feature = "legacy")]
+            None => Mode::Autonomous, // Legacy receivers do not report status at all
+            #[cfg(not(feature = "legacy"))]
+            None => return Err("Status field is mandatory for GLL sentence!"),
         };
-        let mode = Mode::from_some_str_or_status(fields.next(), &status)?;
         if let (Some(lat), Some(lon), Some(time)) = (latitude, longitude, time) {
             Ok(Some(GLL {
                 source,
@@ -1,7 +1,10 @@
 use crate::common;
 use crate::modes::Mode;
+use crate::quality::Dop;
+use crate::satellite::Satellite;
 use crate::Source;
 const MAX_PRNS_PER_MESSAGE: usize = 12;
+const MAX_GSA_PER_EPOCH: usize = 8;
 
 /// GPS DOP and active satellites
 #[derive(Debug, PartialEq, Clone)]
@@ -22,6 +25,8 @@ pub struct GSA {
     pub hdop: f32,
     /// Vertical dilusion of precision.
     pub vdop: f32,
+    /// GNSS system ID (NMEA 4.10 and later). Identifies constellation when talker is GN.
+    pub system_id: Option<u8>,
 }
 
 impl GSA {
@@ -42,6 +47,12 @@ impl GSA {
         let pdop = common::parse_f32(fields.next())?;
         let hdop = common::parse_f32(fields.next())?;
         let vdop = common::parse_f32(fields.next())?;
+        // Extra PRNs shift DOPs to the following fields, so system ID is not integer or fields remain
+        let system_id =
+            common::parse_u8(fields.next()).map_err(|_| "Malformed GSA field count!")?;
+        if fields.next().is_some() {
+            return Err("Malformed GSA field count!");
+        }
 
         if let (Some(fix_type), Some(pdop), Some(hdop), Some(vdop)) = (fix_type, pdop, hdop, vdop) {
             Ok(Some(GSA {
@@ -53,6 +64,7 @@ impl GSA {
                 pdop,
                 hdop,
                 vdop,
+                system_id,
             }))
         } else {
             Ok(None)
@@ -62,10 +74,82 @@ impl GSA {
     pub fn get_fix_satellites_prn(&self) -> &[u16] {
         &self.fix_sats_prn[..self.prn_array_size]
     }
+    /// Satellites from the slice, typically collected from GSV, that are used in the fix.
+    pub fn correlate<'a>(&self, sats: &'a [Satellite]) -> impl Iterator<Item = &'a Satellite> {
+        let (prns, count) = (self.fix_sats_prn, self.prn_array_size);
+        sats.iter()
+            .filter(move |sat| prns[..count].contains(&sat.prn))
+    }
+    /// Dilusion of precision values grouped together.
+    pub fn dop(&self) -> Dop {
+        Dop {
+            pdop: Some(self.pdop),
+            hdop: self.hdop,
+            vdop: Some(self.vdop),
+        }
+    }
+}
+
+/// Groups consecutive GSA sentences of the same fix, one GSA per constellation.
+/// Constellation is identified by GSA system ID or by the talker if system ID is not reported.
+/// Sentence from constellation that is already in the group starts a new group.
+#[derive(Debug, Default)]
+pub struct GsaCollector {
+    sentences: [Option<GSA>; MAX_GSA_PER_EPOCH],
+    count: usize,
+}
+
+impl GsaCollector {
+    /// Constructs new empty GsaCollector.
+    pub fn new() -> GsaCollector {
+        Default::default()
+    }
+    /// Adds GSA sentence to the group.
+    pub fn update(&mut self, gsa: &GSA) {
+        let starts_new_group = self.count == MAX_GSA_PER_EPOCH
+            || self
+                .sentences()
+                .any(|g| g.source == gsa.source && g.system_id == gsa.system_id);
+        if starts_new_group {
+            self.clear();
+        }
+        self.sentences[self.count] = Some(gsa.clone());
+        self.count += 1;
+    }
+    /// Removes all collected sentences.
+    pub fn clear(&mut self) {
+        for gsa in self.sentences.iter_mut() {
+            *gsa = None;
+        }
+        self.count = 0;
+    }
+    /// Collected GSA sentences, one per constellation.
+    pub fn sentences(&self) -> impl Iterator<Item = &GSA> {
+        self.sentences[..self.count]
+            .iter()
+            .filter_map(Option::as_ref)
+    }
+    /// PRNs of satellites used in the fix across all constellations.
+    pub fn fix_satellites_prn(&self) -> impl Iterator<Item = u16> + '_ {
+        self.sentences()
+            .flat_map(|gsa| gsa.get_fix_satellites_prn().iter().cloned())
+    }
+    /// Position dilusion of precision of the combined solution.
+    pub fn pdop(&self) -> Option<f32> {
+        self.sentences().last().map(|gsa| gsa.pdop)
+    }
+    /// Horizontal dilusion of precision of the combined solution.
+    pub fn hdop(&self) -> Option<f32> {
+        self.sentences().last().map(|gsa| gsa.hdop)
+    }
+    /// Vertical dilusion of precision of the combined solution.
+    pub fn vdop(&self) -> Option<f32> {
+        self.sentences().last().map(|gsa| gsa.vdop)
+    }
 }
 
 /// Receiver mode of positioning.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum FixType {
     /// No valid position is available.
     NoFix,
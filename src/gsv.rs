@@ -2,10 +2,12 @@ use crate::common;
 use crate::satellite::Satellite;
 use crate::Source;
 const MAX_SATELLITES_PER_MESSAGE: usize = 4;
+/// Default capacity of [GsvCollector](struct.GsvCollector.html).
+pub const MAX_SATELLITES_IN_VIEW: usize = 32;
 /// Satellites in views including the number of SVs in view, the PRN numbers, elevations, azimuths, and SNR values.
 #[derive(Debug, PartialEq, Clone)]
 pub struct GSV {
-    /// Navigational system.
+    /// Navigational system. Constellation of satellites in the message, e.g. GLONASS for `GLGSV`.
     pub source: Source,
     /// The total number of GSV messages for the current data.
     pub total_messages_number: u8,
@@ -38,6 +40,15 @@ impl GSV {
                 break;
             }
         }
+        // Only NMEA 4.10 signal ID may follow four satellites, more non-empty fields is a fifth satellite
+        if satellite_array_size == MAX_SATELLITES_PER_MESSAGE {
+            let (remaining, non_empty) = fields.fold((0, false), |(count, non_empty), f| {
+                (count + 1, non_empty || !f.is_empty())
+            });
+            if remaining > 1 && non_empty {
+                return Err("Too many satellites in GSV message!");
+            }
+        }
 
         if let (Some(total_messages_number), Some(message_number), Some(sat_in_view)) =
             (total_messages_number, message_number, sat_in_view)
@@ -59,3 +70,94 @@ impl GSV {
         &self.satellites[..self.satellite_array_size]
     }
 }
+
+/// Reassembles satellites in view from sequences of GSV sentences of all constellations.
+/// First GSV sentence of constellation that is already in the group starts a new group.
+/// Up to N satellites are collected, satellites beyond capacity are ignored and [overflowed](#method.overflowed) flag is set.
+#[derive(Debug)]
+pub struct GsvCollector<const N: usize = MAX_SATELLITES_IN_VIEW> {
+    satellites: [Satellite; N],
+    count: usize,
+    sources: u32,
+    overflowed: bool,
+}
+
+impl<const N: usize> Default for GsvCollector<N> {
+    fn default() -> Self {
+        const EMPTY: Satellite = Satellite {
+            prn: 0,
+            elevation: 0,
+            azimuth: 0,
+            snr: None,
+        };
+        GsvCollector {
+            satellites: [EMPTY; N],
+            count: 0,
+            sources: 0,
+            overflowed: false,
+        }
+    }
+}
+
+impl GsvCollector {
+    /// Constructs new empty GsvCollector of default capacity. Use `GsvCollector::<N>::default()` for other capacity.
+    pub fn new() -> GsvCollector {
+        Default::default()
+    }
+}
+
+impl<const N: usize> GsvCollector<N> {
+    /// Adds satellites from GSV sentence to the group.
+    pub fn update(&mut self, gsv: &GSV) {
+        if gsv.message_number == 1 && self.sources & gsv.source as u32 != 0 {
+            self.clear();
+        }
+        self.sources |= gsv.source as u32;
+        for satellite in gsv.get_in_view_satellites() {
+            if self.count < N {
+                self.satellites[self.count] = satellite.clone();
+                self.count += 1;
+            } else {
+                self.overflowed = true;
+            }
+        }
+    }
+    /// Removes all collected satellites.
+    pub fn clear(&mut self) {
+        self.count = 0;
+        self.sources = 0;
+        self.overflowed = false;
+    }
+    /// True if some satellites of the group were ignored because collector is full.
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+    /// Collected satellites in view across all constellations.
+    pub fn satellites(&self) -> &[Satellite] {
+        &self.satellites[..self.count]
+    }
+    /// Mean SNR of tracked satellites. None if no satellites tracked.
+    pub fn average_snr(&self) -> Option<f32> {
+        let (sum, tracked) = self
+            .satellites()
+            .iter()
+            .filter_map(|s| s.snr)
+            .fold((0u32, 0u32), |(sum, tracked), snr| {
+                (sum + snr as u32, tracked + 1)
+            });
+        if tracked > 0 {
+            Some(sum as f32 / tracked as f32)
+        } else {
+            None
+        }
+    }
+    /// Maximum SNR of tracked satellites. None if no satellites tracked.
+    pub fn max_snr(&self) -> Option<u8> {
+        self.satellites().iter().filter_map(|s| s.snr).max()
+    }
+    /// Copies collected satellites to `heapless::Vec`. Satellites beyond its capacity M are dropped.
+    #[cfg(feature = "heapless")]
+    pub fn to_heapless<const M: usize>(&self) -> heapless::Vec<Satellite, M> {
+        self.satellites().iter().take(M).cloned().collect()
+    }
+}
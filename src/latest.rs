@@ -0,0 +1,54 @@
+use crate::{ParseResult, GGA, GLL, GSA, RMC, VTG};
+
+/// Keeps the most recent RMC, GGA, GLL, VTG and GSA sentences.
+/// Sentences without valid data do not replace previously received ones.
+#[derive(Debug, Default)]
+pub struct LatestCache {
+    rmc: Option<RMC>,
+    gga: Option<GGA>,
+    gll: Option<GLL>,
+    vtg: Option<VTG>,
+    gsa: Option<GSA>,
+}
+
+impl LatestCache {
+    /// Constructs new empty LatestCache.
+    pub fn new() -> LatestCache {
+        Default::default()
+    }
+    /// Stores parsed sentence if it is one of cached types. Other sentences are ignored.
+    pub fn update(&mut self, result: &ParseResult) {
+        match result {
+            ParseResult::RMC(Some(rmc)) => self.rmc = Some(rmc.clone()),
+            ParseResult::GGA(Some(gga)) => self.gga = Some(gga.clone()),
+            ParseResult::GLL(Some(gll)) => self.gll = Some(gll.clone()),
+            ParseResult::VTG(Some(vtg)) => self.vtg = Some(vtg.clone()),
+            ParseResult::GSA(Some(gsa)) => self.gsa = Some(gsa.clone()),
+            _ => {}
+        }
+    }
+    /// Removes all cached sentences.
+    pub fn clear(&mut self) {
+        *self = Default::default();
+    }
+    /// The most recent RMC sentence.
+    pub fn rmc(&self) -> Option<&RMC> {
+        self.rmc.as_ref()
+    }
+    /// The most recent GGA sentence.
+    pub fn gga(&self) -> Option<&GGA> {
+        self.gga.as_ref()
+    }
+    /// The most recent GLL sentence.
+    pub fn gll(&self) -> Option<&GLL> {
+        self.gll.as_ref()
+    }
+    /// The most recent VTG sentence.
+    pub fn vtg(&self) -> Option<&VTG> {
+        self.vtg.as_ref()
+    }
+    /// The most recent GSA sentence.
+    pub fn gsa(&self) -> Option<&GSA> {
+        self.gsa.as_ref()
+    }
+}
@@ -73,44 +73,116 @@
 //!
 //! It's possible to got other very rare error messages that relates to protocol errors. Receivers nowadays mostly do not violate NMEA specs.
 //!
+//! Use [error_code](fn.error_code.html) to get stable numeric code of error message.
+//!
 //! # Planned features
 //!
 //! GSA and GSV parsing.
 //!
+#[cfg(feature = "std")]
+extern crate std;
+
+use coords::{Latitude, Longitude};
 use core::convert::TryFrom;
 use core::ops::BitOr;
 use core::slice::Iter;
+use datetime::Time;
 pub(crate) mod common;
 pub mod coords;
 pub mod datetime;
+pub mod quality;
 pub mod satellite;
 
+pub(crate) mod aam;
+pub(crate) mod bwc;
+pub(crate) mod error;
+pub(crate) mod fix;
+#[cfg(feature = "garmin")]
+pub(crate) mod garmin;
 pub(crate) mod gga;
 pub(crate) mod gsa;
 pub(crate) mod gsv;
 
 pub(crate) mod gll;
+pub(crate) mod latest;
 pub(crate) mod modes;
+pub(crate) mod msk;
 #[cfg(feature = "mtk")]
 pub(crate) mod mtk;
+pub(crate) mod mwd;
+pub(crate) mod osd;
+#[cfg(feature = "std")]
+pub(crate) mod reader;
+pub(crate) mod rma;
 pub(crate) mod rmc;
+pub(crate) mod rte;
+#[cfg(feature = "tag-blocks")]
+pub(crate) mod tag;
+pub(crate) mod ths;
+#[cfg(feature = "ublox")]
+pub(crate) mod ublox;
+pub(crate) mod vbw;
+pub(crate) mod vdr;
 pub(crate) mod vtg;
+pub(crate) mod waypoint;
 
+pub use aam::AAM;
+pub use bwc::BWC;
+pub use error::error_code;
+pub use fix::cross_check_fix;
+pub use fix::Fix;
+pub use fix::FixAggregator;
+pub use fix::FixStats;
+pub use fix::COMPACT_FIX_SIZE;
+#[cfg(feature = "garmin")]
+pub use garmin::GarminSentenceType;
+#[cfg(feature = "garmin")]
+pub use garmin::PGRME;
+#[cfg(feature = "garmin")]
+pub use garmin::PGRMZ;
 pub use gga::GPSQuality;
 pub use gga::GGA;
 pub use gll::GLL;
 pub use gsa::FixType;
+pub use gsa::GsaCollector;
 pub use gsa::GSA;
+pub use gsv::GsvCollector;
 pub use gsv::GSV;
+pub use gsv::MAX_SATELLITES_IN_VIEW;
+pub use latest::LatestCache;
 pub use modes::Mode;
+pub use modes::NavStatus;
+pub use msk::MSK;
 #[cfg(feature = "mtk")]
 pub use mtk::JammingStatus;
 #[cfg(feature = "mtk")]
 pub use mtk::MTKPacketType;
 #[cfg(feature = "mtk")]
 pub use mtk::PMTKSPF;
+pub use mwd::MWD;
+pub use osd::OSD;
+#[cfg(feature = "std")]
+pub use reader::ReaderError;
+pub use rma::RMA;
 pub use rmc::RMC;
+pub use rte::RTE;
+#[cfg(feature = "tag-blocks")]
+pub use tag::TagBlock;
+#[cfg(feature = "tag-blocks")]
+pub use tag::TagGroup;
+pub use ths::THS;
+#[cfg(feature = "ublox")]
+pub use ublox::PUBXMessageType;
+#[cfg(feature = "ublox")]
+pub use ublox::PUBXNavStatus;
+#[cfg(feature = "ublox")]
+pub use ublox::PUBX00;
+#[cfg(feature = "ublox")]
+pub use ublox::PUBX04;
+pub use vbw::VBW;
+pub use vdr::VDR;
 pub use vtg::VTG;
+pub use waypoint::WaypointId;
 /// Source of NMEA sentence like GPS, GLONASS or other.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Source {
@@ -127,14 +199,58 @@ pub enum Source {
     #[cfg(feature = "mtk")]
     /// MediaTek NMEA packet protocol
     MTK = 0b100000,
+    #[cfg(feature = "garmin")]
+    /// Garmin proprietary sentences
+    Garmin = 0b1000000,
+    #[cfg(feature = "ublox")]
+    /// U-blox proprietary messages
+    UBlox = 0b10000000,
+    /// Integrated instrumentation
+    IntegratedInstrumentation = 0b100000000,
+    /// Electronic Chart Display and Information System
+    ECDIS = 0b1000000000,
+    /// Depth sounder
+    DepthSounder = 0b10000000000,
+    /// Magnetic heading compass
+    MagneticCompass = 0b100000000000,
+    /// Weather instruments
+    WeatherInstruments = 0b1000000000000,
+    /// Water speed sensor
+    WaterSpeed = 0b10000000000000,
+    /// Japan's Quasi-Zenith Satellite System
+    QZSS = 0b100000000000000,
+    /// India's Navigation with Indian Constellation (IRNSS)
+    NavIC = 0b1000000000000000,
+    /// Beacon receiver
+    BeaconReceiver = 0b10000000000000000,
+    /// Doppler velocity sensor
+    DopplerVelocity = 0b100000000000000000,
+    /// Loran-C receiver
+    LoranC = 0b1000000000000000000,
 }
 
 /// Mask for Source filter in Parser.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SourceMask {
     mask: u32,
 }
 
 impl SourceMask {
+    const fn all() -> Self {
+        SourceMask {
+            mask: u32::max_value(),
+        }
+    }
+    /// Removes source from the filter.
+    pub fn without(self, source: Source) -> Self {
+        SourceMask {
+            mask: self.mask & !(source as u32),
+        }
+    }
+    /// True if source passes the filter.
+    pub fn contains(&self, source: Source) -> bool {
+        !self.is_masked(source)
+    }
     fn is_masked(&self, source: Source) -> bool {
         source as u32 & self.mask == 0
     }
@@ -142,8 +258,37 @@ impl SourceMask {
 
 impl Default for SourceMask {
     fn default() -> Self {
-        SourceMask {
-            mask: u32::max_value(),
+        SourceMask::all()
+    }
+}
+
+impl Source {
+    /// Talker identifier like `GP` or `GL`, inverse of parsing source from the sentence field.
+    /// Sources that have several talker identifiers return the first one, e.g. `BD` for Beidou.
+    pub fn talker_id(&self) -> &'static str {
+        match self {
+            Source::GPS => "GP",
+            Source::GLONASS => "GL",
+            Source::Gallileo => "GA",
+            Source::Beidou => "BD",
+            Source::QZSS => "QZ",
+            Source::NavIC => "GI",
+            Source::GNSS => "GN",
+            #[cfg(feature = "mtk")]
+            Source::MTK => "PM",
+            #[cfg(feature = "garmin")]
+            Source::Garmin => "PG",
+            #[cfg(feature = "ublox")]
+            Source::UBlox => "PU",
+            Source::IntegratedInstrumentation => "II",
+            Source::ECDIS => "EC",
+            Source::DepthSounder => "SD",
+            Source::MagneticCompass => "HC",
+            Source::WeatherInstruments => "WI",
+            Source::WaterSpeed => "VW",
+            Source::BeaconReceiver => "CR",
+            Source::DopplerVelocity => "VD",
+            Source::LoranC => "LC",
         }
     }
 }
@@ -170,21 +315,36 @@ impl TryFrom<&str> for Source {
     type Error = &'static str;
 
     fn try_from(from: &str) -> Result<Self, Self::Error> {
-        match &from[0..2] {
+        match from.get(0..2).ok_or("Source field is too small!")? {
             "GP" => Ok(Source::GPS),
             "GL" => Ok(Source::GLONASS),
             "GA" => Ok(Source::Gallileo),
-            "BD" => Ok(Source::Beidou),
+            "BD" | "GB" => Ok(Source::Beidou),
+            "QZ" => Ok(Source::QZSS),
+            "GI" | "IN" => Ok(Source::NavIC),
             "GN" => Ok(Source::GNSS),
             #[cfg(feature = "mtk")]
             "PM" => Ok(Source::MTK),
+            #[cfg(feature = "garmin")]
+            "PG" => Ok(Source::Garmin),
+            #[cfg(feature = "ublox")]
+            "PU" => Ok(Source::UBlox),
+            "II" => Ok(Source::IntegratedInstrumentation),
+            "EC" => Ok(Source::ECDIS),
+            "SD" => Ok(Source::DepthSounder),
+            "HC" => Ok(Source::MagneticCompass),
+            "WI" => Ok(Source::WeatherInstruments),
+            "VW" => Ok(Source::WaterSpeed),
+            "CR" => Ok(Source::BeaconReceiver),
+            "VD" => Ok(Source::DopplerVelocity),
+            "LC" => Ok(Source::LoranC),
             _ => Err("Source is not supported!"),
         }
     }
 }
 
 /// Various kinds of NMEA sentence like RMC, VTG or other. Used for filter by sentence type in Parser.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Sentence {
     /// Recommended minimum sentence.
     RMC = 0b1,
@@ -201,6 +361,32 @@ pub enum Sentence {
     GSV = 0b100000,
     /// GPS DOP and active satellites.
     GSA = 0b1000000,
+    #[cfg(feature = "garmin")]
+    /// Garmin proprietary messages.
+    PGRM = 0b10000000,
+    #[cfg(feature = "ublox")]
+    /// U-blox proprietary messages.
+    PUBX = 0b100000000,
+    /// Beacon receiver control.
+    MSK = 0b1000000000,
+    /// Bearing and distance to waypoint.
+    BWC = 0b10000000000,
+    /// Waypoint arrival alarm.
+    AAM = 0b100000000000,
+    /// True heading and status.
+    THS = 0b1000000000000,
+    /// Dual ground and water speed.
+    VBW = 0b10000000000000,
+    /// Wind direction and speed.
+    MWD = 0b100000000000000,
+    /// Route definition.
+    RTE = 0b1000000000000000,
+    /// Set and drift.
+    VDR = 0b10000000000000000,
+    /// Own ship data.
+    OSD = 0b100000000000000000,
+    /// Recommended minimum specific Loran-C data.
+    RMA = 0b1000000000000000000,
 }
 
 impl TryFrom<&str> for Sentence {
@@ -216,17 +402,47 @@ impl TryFrom<&str> for Sentence {
             #[cfg(feature = "mtk")]
             "PMTK" => Ok(Sentence::PMTK),
             "GSA" => Ok(Sentence::GSA),
+            "MSK" => Ok(Sentence::MSK),
+            "BWC" => Ok(Sentence::BWC),
+            "AAM" => Ok(Sentence::AAM),
+            "THS" => Ok(Sentence::THS),
+            "VBW" => Ok(Sentence::VBW),
+            "MWD" => Ok(Sentence::MWD),
+            "RTE" => Ok(Sentence::RTE),
+            "VDR" => Ok(Sentence::VDR),
+            "OSD" => Ok(Sentence::OSD),
+            "RMA" => Ok(Sentence::RMA),
+            #[cfg(feature = "garmin")]
+            "PGRM" => Ok(Sentence::PGRM),
+            #[cfg(feature = "ublox")]
+            "PUBX" => Ok(Sentence::PUBX),
             _ => Err("Unsupported sentence type."),
         }
     }
 }
 
 /// Mask for Sentence filter in Parser.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SentenceMask {
     mask: u32,
 }
 
 impl SentenceMask {
+    const fn all() -> Self {
+        SentenceMask {
+            mask: u32::max_value(),
+        }
+    }
+    /// Removes sentence from the filter.
+    pub fn without(self, sentence: Sentence) -> Self {
+        SentenceMask {
+            mask: self.mask & !(sentence as u32),
+        }
+    }
+    /// True if sentence passes the filter.
+    pub fn contains(&self, sentence: Sentence) -> bool {
+        !self.is_masked(sentence)
+    }
     fn is_masked(&self, sentence: Sentence) -> bool {
         sentence as u32 & self.mask == 0
     }
@@ -234,9 +450,7 @@ impl SentenceMask {
 
 impl Default for SentenceMask {
     fn default() -> Self {
-        SentenceMask {
-            mask: u32::max_value(),
-        }
+        SentenceMask::all()
     }
 }
 
@@ -278,6 +492,155 @@ pub enum ParseResult {
     PMTK(Option<PMTKSPF>),
     /// The GPS DOP and active satellites. Provides information about the DOP and the active satellites used for the current fix.
     GSA(Option<GSA>),
+    #[cfg(feature = "garmin")]
+    /// The Garmin estimated position error.
+    PGRME(Option<PGRME>),
+    #[cfg(feature = "garmin")]
+    /// The Garmin altitude.
+    PGRMZ(Option<PGRMZ>),
+    #[cfg(feature = "ublox")]
+    /// The U-blox position data.
+    PUBX00(Option<PUBX00>),
+    #[cfg(feature = "ublox")]
+    /// The U-blox time of day and clock information.
+    PUBX04(Option<PUBX04>),
+    /// The beacon receiver control.
+    MSK(Option<MSK>),
+    /// The bearing and distance to waypoint.
+    BWC(Option<BWC>),
+    /// The waypoint arrival alarm.
+    AAM(Option<AAM>),
+    /// The true heading and status.
+    THS(Option<THS>),
+    /// The dual ground and water speed.
+    VBW(Option<VBW>),
+    /// The wind direction and speed.
+    MWD(Option<MWD>),
+    /// The route definition.
+    RTE(Option<RTE>),
+    /// The set and drift.
+    VDR(Option<VDR>),
+    /// The own ship data.
+    OSD(Option<OSD>),
+    /// The recommended minimum specific Loran-C data.
+    RMA(Option<RMA>),
+}
+
+impl ParseResult {
+    /// Parses sentence fields that follow the sentence type field, already split by commas.
+    /// Allows to reuse sentence decoding with custom framing. Checksum should be verified by caller.
+    /// MTK and Garmin sentences are not supported because their subtype is a part of the sentence type field.
+    pub fn from_fields(
+        source: Source,
+        sentence: Sentence,
+        fields: &mut core::str::Split<'_, char>,
+    ) -> Result<ParseResult, &'static str> {
+        match sentence {
+            Sentence::RMC => Ok(ParseResult::RMC(RMC::parse(source, fields)?)),
+            Sentence::GGA => Ok(ParseResult::GGA(GGA::parse(source, fields)?)),
+            Sentence::GLL => Ok(ParseResult::GLL(GLL::parse(source, fields)?)),
+            Sentence::VTG => Ok(ParseResult::VTG(VTG::parse(source, fields)?)),
+            Sentence::GSV => Ok(ParseResult::GSV(GSV::parse(source, fields)?)),
+            Sentence::GSA => Ok(ParseResult::GSA(GSA::parse(source, fields)?)),
+            Sentence::MSK => Ok(ParseResult::MSK(MSK::parse(source, fields)?)),
+            Sentence::BWC => Ok(ParseResult::BWC(BWC::parse(source, fields)?)),
+            Sentence::AAM => Ok(ParseResult::AAM(AAM::parse(source, fields)?)),
+            Sentence::THS => Ok(ParseResult::THS(THS::parse(source, fields)?)),
+            Sentence::VBW => Ok(ParseResult::VBW(VBW::parse(source, fields)?)),
+            Sentence::MWD => Ok(ParseResult::MWD(MWD::parse(source, fields)?)),
+            Sentence::RTE => Ok(ParseResult::RTE(RTE::parse(source, fields)?)),
+            Sentence::VDR => Ok(ParseResult::VDR(VDR::parse(source, fields)?)),
+            Sentence::OSD => Ok(ParseResult::OSD(OSD::parse(source, fields)?)),
+            Sentence::RMA => Ok(ParseResult::RMA(RMA::parse(source, fields)?)),
+            #[cfg(feature = "ublox")]
+            Sentence::PUBX => {
+                let message_type = fields.next().ok_or("PUBX message type is mandatory!")?;
+                match PUBXMessageType::try_from(message_type)? {
+                    PUBXMessageType::Position => {
+                        Ok(ParseResult::PUBX00(PUBX00::parse(source, fields)?))
+                    }
+                    PUBXMessageType::Time => {
+                        Ok(ParseResult::PUBX04(PUBX04::parse(source, fields)?))
+                    }
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err("Sentence subtype is not available in fields!"),
+        }
+    }
+    /// True if sentence carries valid fix according to its mode, GPS quality or fix type.
+    /// Sentences without data and sentences that do not describe fix return false.
+    pub fn has_valid_fix(&self) -> bool {
+        match self {
+            ParseResult::RMC(Some(rmc)) => rmc.mode.is_valid(),
+            ParseResult::GGA(Some(gga)) => gga.gps_quality != GPSQuality::NoFix,
+            ParseResult::GLL(Some(gll)) => gll.mode.is_valid(),
+            ParseResult::VTG(Some(vtg)) => vtg.mode.is_valid(),
+            ParseResult::GSA(Some(gsa)) => gsa.fix_type != FixType::NoFix,
+            _ => false,
+        }
+    }
+    /// Time of fix or message time in UTC if sentence carries it. None for sentences without time and sentences without data.
+    pub fn time(&self) -> Option<&Time> {
+        match self {
+            ParseResult::RMC(Some(rmc)) => Some(&rmc.datetime.time),
+            ParseResult::GGA(Some(gga)) => Some(&gga.time),
+            ParseResult::GLL(Some(gll)) => Some(&gll.time),
+            ParseResult::BWC(Some(bwc)) => Some(&bwc.time),
+            #[cfg(feature = "ublox")]
+            ParseResult::PUBX00(Some(pubx)) => Some(&pubx.time),
+            #[cfg(feature = "ublox")]
+            ParseResult::PUBX04(Some(pubx)) => Some(&pubx.time),
+            _ => None,
+        }
+    }
+    fn without_null_island(self) -> ParseResult {
+        fn is_null_island(latitude: &Latitude, longitude: &Longitude) -> bool {
+            latitude.as_f64() == 0f64 && longitude.as_f64() == 0f64
+        }
+        match self {
+            ParseResult::RMC(Some(ref rmc)) if is_null_island(&rmc.latitude, &rmc.longitude) => {
+                ParseResult::RMC(None)
+            }
+            ParseResult::GGA(Some(ref gga)) if is_null_island(&gga.latitude, &gga.longitude) => {
+                ParseResult::GGA(None)
+            }
+            ParseResult::GLL(Some(ref gll)) if is_null_island(&gll.latitude, &gll.longitude) => {
+                ParseResult::GLL(None)
+            }
+            result => result,
+        }
+    }
+    fn is_empty(&self) -> bool {
+        match self {
+            ParseResult::RMC(data) => data.is_none(),
+            ParseResult::GGA(data) => data.is_none(),
+            ParseResult::GLL(data) => data.is_none(),
+            ParseResult::VTG(data) => data.is_none(),
+            ParseResult::GSV(data) => data.is_none(),
+            #[cfg(feature = "mtk")]
+            ParseResult::PMTK(data) => data.is_none(),
+            ParseResult::GSA(data) => data.is_none(),
+            #[cfg(feature = "garmin")]
+            ParseResult::PGRME(data) => data.is_none(),
+            #[cfg(feature = "garmin")]
+            ParseResult::PGRMZ(data) => data.is_none(),
+            #[cfg(feature = "ublox")]
+            ParseResult::PUBX00(data) => data.is_none(),
+            #[cfg(feature = "ublox")]
+            ParseResult::PUBX04(data) => data.is_none(),
+            ParseResult::MSK(data) => data.is_none(),
+            ParseResult::BWC(data) => data.is_none(),
+            ParseResult::AAM(data) => data.is_none(),
+            ParseResult::THS(data) => data.is_none(),
+            ParseResult::VBW(data) => data.is_none(),
+            ParseResult::MWD(data) => data.is_none(),
+            ParseResult::RTE(data) => data.is_none(),
+            ParseResult::VDR(data) => data.is_none(),
+            ParseResult::OSD(data) => data.is_none(),
+            ParseResult::RMA(data) => data.is_none(),
+        }
+    }
 }
 
 #[cfg(feature = "strict")]
@@ -298,16 +661,43 @@ pub struct Parser {
     parser_state: ParserState,
     source_mask: SourceMask,
     sentence_mask: SentenceMask,
+    allow_missing_checksum: bool,
+    accept_lf_only: bool,
+    tolerant_whitespace: bool,
+    resync_at_line_end: bool,
+    skip_empty: bool,
+    reject_null_island: bool,
+    dedup: bool,
+    clamp_out_of_range: bool,
+    clamped: bool,
+    last_sentence: [u8; MAX_SENTENCE_LENGTH],
+    last_sentence_len: Option<usize>,
+    encapsulated: bool,
+    complete: bool,
+    #[cfg(feature = "tag-blocks")]
+    tag_buffer: [u8; tag::MAX_TAG_BLOCK_LENGTH],
+    #[cfg(feature = "tag-blocks")]
+    tag_buflen: usize,
+    #[cfg(feature = "tag-blocks")]
+    pending_tag_block: Option<TagBlock>,
+    #[cfg(feature = "tag-blocks")]
+    tag_block: Option<TagBlock>,
 }
 
 #[derive(Debug)]
 enum ParserState {
     WaitStart,
+    #[cfg(feature = "tag-blocks")]
+    ReadTagBlock,
+    #[cfg(feature = "tag-blocks")]
+    SkipTagBlock,
     ReadUntilChkSum,
     ChkSumUpper,
     ChkSumLower,
     WaitCR,
+    TrailingWhitespace,
     WaitLF,
+    Resync,
 }
 
 struct ParserIterator<'a> {
@@ -338,17 +728,98 @@ impl Iterator for ParserIterator<'_> {
     }
 }
 
+struct RawSentenceIterator<'a> {
+    parser: &'a mut Parser,
+    input: Iter<'a, u8>,
+}
+
+impl RawSentenceIterator<'_> {
+    fn new<'a>(p: &'a mut Parser, inp: &'a [u8]) -> RawSentenceIterator<'a> {
+        RawSentenceIterator {
+            parser: p,
+            input: inp.iter(),
+        }
+    }
+}
+
+impl Iterator for RawSentenceIterator<'_> {
+    type Item = Result<RawSentence, &'static str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for b in &mut self.input {
+            if let Some(r) = self.parser.read_byte(*b) {
+                return Some(r.map(|_| RawSentence {
+                    buffer: self.parser.buffer,
+                    len: self.parser.buflen,
+                    encapsulated: self.parser.encapsulated,
+                }));
+            }
+        }
+        None
+    }
+}
+
+/// Checksum validated NMEA sentence bytes between `$` and `*` copied from parser's buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RawSentence {
+    buffer: [u8; MAX_SENTENCE_LENGTH],
+    len: usize,
+    encapsulated: bool,
+}
+
+impl RawSentence {
+    /// Sentence bytes without start delimiter and checksum.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+    /// True if sentence starts with `!` delimiter used by encapsulated sentences like AIS.
+    pub fn is_encapsulated(&self) -> bool {
+        self.encapsulated
+    }
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Parser::new()
+    }
+}
+
 impl Parser {
     /// Constructs new Parser.
-    pub fn new() -> Parser {
+    pub const fn new() -> Parser {
+        Parser::with_filters(SourceMask::all(), SentenceMask::all())
+    }
+    /// Constructs new Parser that accepts only given [sources](enum.Source.html) and [sentences](enum.Sentence.html).
+    pub const fn with_filters(source_mask: SourceMask, sentence_mask: SentenceMask) -> Parser {
         Parser {
             buffer: [0u8; MAX_SENTENCE_LENGTH],
             buflen: 0,
             chksum: 0,
             expected_chksum: 0,
             parser_state: ParserState::WaitStart,
-            source_mask: Default::default(),
-            sentence_mask: Default::default(),
+            source_mask,
+            sentence_mask,
+            allow_missing_checksum: false,
+            accept_lf_only: false,
+            tolerant_whitespace: false,
+            resync_at_line_end: false,
+            skip_empty: false,
+            reject_null_island: false,
+            dedup: false,
+            clamp_out_of_range: false,
+            clamped: false,
+            last_sentence: [0u8; MAX_SENTENCE_LENGTH],
+            last_sentence_len: None,
+            encapsulated: false,
+            complete: false,
+            #[cfg(feature = "tag-blocks")]
+            tag_buffer: [0u8; tag::MAX_TAG_BLOCK_LENGTH],
+            #[cfg(feature = "tag-blocks")]
+            tag_buflen: 0,
+            #[cfg(feature = "tag-blocks")]
+            pending_tag_block: None,
+            #[cfg(feature = "tag-blocks")]
+            tag_block: None,
         }
     }
     /// Accepts only that [source](enum.Source.html)
@@ -375,6 +846,93 @@ impl Parser {
         self.sentence_mask = sentence_mask;
         self
     }
+    /// Accept sentences without checksum that ends right after data with CR LF. Such sentences are parsed without checksum verification.
+    pub fn allow_missing_checksum(mut self, allow: bool) -> Self {
+        self.allow_missing_checksum = allow;
+        self
+    }
+    /// Accept sentences terminated with LF only, without preceding CR.
+    pub fn accept_lf_only(mut self, accept: bool) -> Self {
+        self.accept_lf_only = accept;
+        self
+    }
+    /// Accept spaces and tabs between checksum and CR LF.
+    pub fn tolerant_whitespace(mut self, tolerant: bool) -> Self {
+        self.tolerant_whitespace = tolerant;
+        self
+    }
+    /// After `NMEA sentence is too long!` and `NMEA format error!` errors skip everything until the end of line.
+    /// Otherwise parser waits for the next `$`, which may be a part of corrupted data.
+    pub fn resync_at_line_end(mut self, resync: bool) -> Self {
+        self.resync_at_line_end = resync;
+        self
+    }
+    /// Suppress sentences parsed without valid data, like GGA from receiver without solution.
+    pub fn skip_empty(mut self, skip: bool) -> Self {
+        self.skip_empty = skip;
+        self
+    }
+    /// Treat zero latitude and longitude reported by some receivers during cold start as sentence without valid data.
+    pub fn reject_null_island(mut self, reject: bool) -> Self {
+        self.reject_null_island = reject;
+        self
+    }
+    /// Suppress sentence that immediately repeats the previous one byte by byte.
+    /// Identical periodic sentences separated by other sentences, errors or filtered sentences are not suppressed.
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+    /// Clamp out of range hours, minutes and seconds of RMC, GGA and GLL time to valid bounds instead of returning error.
+    /// Clamped fields are rewritten in sentence buffer, use [clamped](#method.clamped) to check if it happened.
+    /// Only time is clamped. Degrees and minutes of latitude and longitude are not range checked by parser,
+    /// so values like 62 minutes are returned as reported.
+    pub fn clamp_out_of_range(mut self, clamp: bool) -> Self {
+        self.clamp_out_of_range = clamp;
+        self
+    }
+    /// True if out of range fields of the last sentence were clamped to valid bounds.
+    pub fn clamped(&self) -> bool {
+        self.clamped
+    }
+    /// True if parser waits for start of the next sentence, false if it is in the middle of sentence.
+    /// Any noise like UTF-8 BOM before sentence start is skipped while waiting.
+    pub fn is_synced(&self) -> bool {
+        matches!(self.parser_state, ParserState::WaitStart)
+    }
+    /// Count of bytes of the sentence in progress accumulated by parser. Zero if parser waits for start of the next sentence.
+    pub fn buffered_len(&self) -> usize {
+        match self.parser_state {
+            ParserState::WaitStart | ParserState::Resync => 0,
+            #[cfg(feature = "tag-blocks")]
+            ParserState::ReadTagBlock | ParserState::SkipTagBlock => 0,
+            _ => self.buflen,
+        }
+    }
+    /// Human readable name of the parser state for diagnostics.
+    pub fn state_name(&self) -> &'static str {
+        match self.parser_state {
+            ParserState::WaitStart => "Waiting for sentence start",
+            #[cfg(feature = "tag-blocks")]
+            ParserState::ReadTagBlock => "Reading TAG block",
+            #[cfg(feature = "tag-blocks")]
+            ParserState::SkipTagBlock => "Skipping TAG block",
+            ParserState::ReadUntilChkSum => "Reading sentence",
+            ParserState::ChkSumUpper | ParserState::ChkSumLower => "Reading checksum",
+            ParserState::WaitCR => "Waiting for CR",
+            ParserState::TrailingWhitespace => "Skipping trailing whitespace",
+            ParserState::WaitLF => "Waiting for LF",
+            ParserState::Resync => "Skipping until line end",
+        }
+    }
+    /// [Sources](enum.Source.html) accepted by parser.
+    pub fn enabled_sources(&self) -> SourceMask {
+        self.source_mask
+    }
+    /// [Sentences](enum.Sentence.html) accepted by parser.
+    pub fn enabled_sentences(&self) -> SentenceMask {
+        self.sentence_mask
+    }
     /// Use parser state and bytes slice than returns Iterator that yield [ParseResult](enum.ParseResult.html) or errors if has enough data for parsing.
     pub fn parse_from_bytes<'a>(
         &'a mut self,
@@ -382,28 +940,269 @@ impl Parser {
     ) -> impl Iterator<Item = Result<ParseResult, &'static str>> + 'a {
         ParserIterator::new(self, input)
     }
+    /// Use parser state and reader than returns Iterator that yield [ParseResult](enum.ParseResult.html) or errors.
+    /// Bytes are read from reader by small chunks, iteration stops at the end of input or after the first I/O error.
+    #[cfg(feature = "std")]
+    pub fn parse_reader<'a, R: std::io::Read + 'a>(
+        &'a mut self,
+        reader: R,
+    ) -> impl Iterator<Item = Result<ParseResult, ReaderError>> + 'a {
+        reader::ReaderIterator::new(self, reader)
+    }
+    /// Use parser state and bytes slice and calls closure with [ParseResult](enum.ParseResult.html) or error for every parsed sentence.
+    /// Unlike iterator it does not keep parser borrowed after call.
+    pub fn drain_bytes<F>(&mut self, input: &[u8], mut f: F)
+    where
+        F: FnMut(Result<ParseResult, &'static str>),
+    {
+        for b in input {
+            if let Some(result) = self.parse_from_byte(*b) {
+                f(result);
+            }
+        }
+    }
+    /// Feeds chunk of bytes into parser and writes results into caller provided slice without allocation.
+    /// Returns count of results produced from the chunk. If it is greater than `out` length, results beyond capacity are dropped.
+    pub fn feed(
+        &mut self,
+        chunk: &[u8],
+        out: &mut [Option<Result<ParseResult, &'static str>>],
+    ) -> usize {
+        let mut count = 0;
+        for b in chunk {
+            if let Some(result) = self.parse_from_byte(*b) {
+                if let Some(slot) = out.get_mut(count) {
+                    *slot = Some(result);
+                }
+                count += 1;
+            }
+        }
+        count
+    }
+    /// Use parser state and bytes slice than returns Iterator that yield checksum validated [raw sentences](struct.RawSentence.html) without decoding them.
+    /// Source and sentence filters are not applied to raw sentences.
+    pub fn raw_sentences<'a>(
+        &'a mut self,
+        input: &'a [u8],
+    ) -> impl Iterator<Item = Result<RawSentence, &'static str>> + 'a {
+        RawSentenceIterator::new(self, input)
+    }
     /// Parse NMEA by one byte at a time. Returns Some if has enough data for parsing.
     pub fn parse_from_byte(&mut self, symbol: u8) -> Option<Result<ParseResult, &'static str>> {
+        match self.read_byte(symbol)? {
+            Ok(()) => {
+                if self.dedup && self.is_repeated() {
+                    return None;
+                }
+                let result = self.parse_complete_sentence();
+                if !matches!(result, Some(Ok(_))) {
+                    self.last_sentence_len = None;
+                }
+                match result? {
+                    Ok(result) if self.skip_empty && result.is_empty() => None,
+                    result => Some(result),
+                }
+            }
+            Err(e) => {
+                self.last_sentence_len = None;
+                Some(Err(e))
+            }
+        }
+    }
+
+    fn parse_complete_sentence(&mut self) -> Option<Result<ParseResult, &'static str>> {
+        self.clamped = false;
+        let mut result = self.parse_sentence().transpose()?;
+        if self.clamp_out_of_range
+            && matches!(result, Err(e) if is_clampable(e))
+            && self.clamp_fields()
+        {
+            self.clamped = true;
+            result = self.parse_sentence().transpose()?;
+        }
+        if self.reject_null_island {
+            result = result.map(ParseResult::without_null_island);
+        }
+        Some(result)
+    }
+
+    /// Parses sentence payload without framing, like `GPRMC,125504.049,A,...` without `$`, checksum and line end.
+    /// Checksum is not verified. Filters and options like [skip_empty](#method.skip_empty) are applied as for framed sentences.
+    pub fn parse_payload(&self, payload: &str) -> Result<Option<ParseResult>, &'static str> {
+        let mut result = self.parse_fields(payload)?;
+        if self.reject_null_island {
+            result = result.map(ParseResult::without_null_island);
+        }
+        match result {
+            Some(result) if self.skip_empty && result.is_empty() => Ok(None),
+            result => Ok(result),
+        }
+    }
+
+    /// Completes pending sentence with verified checksum that lacks line end, like the last sentence of file cut after `*12\r`.
+    /// Returns None if there is no such sentence. Incomplete sentences are kept as is, so parsing may be resumed.
+    pub fn flush(&mut self) -> Option<Result<ParseResult, &'static str>> {
+        match self.parser_state {
+            ParserState::WaitCR | ParserState::TrailingWhitespace | ParserState::WaitLF => {
+                self.parser_state = ParserState::WaitLF;
+                self.parse_from_byte(b'\n')
+            }
+            _ => None,
+        }
+    }
+
+    /// [TAG block](struct.TagBlock.html) that preceded the last sentence if any.
+    #[cfg(feature = "tag-blocks")]
+    pub fn tag_block(&self) -> Option<&TagBlock> {
+        self.tag_block.as_ref()
+    }
+
+    /// Field of the last complete sentence without decoding the whole sentence.
+    /// Index 0 is the sentence type field like `GPGGA`, so GPS quality of GGA has index 6.
+    /// Fields are split lazily up to requested index. Returns None if the last sentence is of other type.
+    pub fn parse_field_only(&self, sentence: Sentence, field_index: usize) -> Option<&str> {
+        if !self.complete || self.encapsulated {
+            return None;
+        }
+        // Buffer is checked to contain only ASCII while bytes are accumulated
+        let input = unsafe { core::str::from_utf8_unchecked(&self.buffer[..self.buflen]) };
+        let mut fields = input.split(',');
+        let sentence_field = fields.next()?;
+        let source = Source::try_from(sentence_field).ok()?;
+        if sentence_of(source, sentence_field).ok()? != sentence {
+            return None;
+        }
+        match field_index {
+            0 => Some(sentence_field),
+            index => fields.nth(index - 1),
+        }
+    }
+
+    /// Best effort time of fix from the last complete RMC, GGA or GLL sentence.
+    /// Time is recovered even if other fields of the sentence are malformed and parsing it returned an error.
+    pub fn extract_time(&self) -> Option<Time> {
+        let field = self
+            .parse_field_only(Sentence::GGA, 1)
+            .or_else(|| self.parse_field_only(Sentence::RMC, 1))
+            .or_else(|| self.parse_field_only(Sentence::GLL, 5))?;
+        Time::parse_from_hhmmss(Some(field)).ok()?
+    }
+
+    fn clamp_fields(&mut self) -> bool {
+        let time = if self.parse_field_only(Sentence::GGA, 0).is_some()
+            || self.parse_field_only(Sentence::RMC, 0).is_some()
+        {
+            1
+        } else if self.parse_field_only(Sentence::GLL, 0).is_some() {
+            5
+        } else {
+            return false;
+        };
+        match self.field_range(time) {
+            Some(field) if field.len() >= 6 => {
+                // Time saturates, so 23:61:00 is clamped to 23:59:59
+                let field = &mut self.buffer[field];
+                if clamp_number(&mut field[..2], 23) {
+                    datetime::write_digits(&mut field[2..6], 5959);
+                    true
+                } else if clamp_number(&mut field[2..4], 59) {
+                    datetime::write_digits(&mut field[4..6], 59);
+                    true
+                } else {
+                    clamp_number(&mut field[4..6], 59)
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn field_range(&self, index: usize) -> Option<core::ops::Range<usize>> {
+        let buffer = &self.buffer[..self.buflen];
+        let mut start = 0;
+        for _ in 0..index {
+            start += buffer[start..].iter().position(|b| *b == b',')? + 1;
+        }
+        let end = buffer[start..]
+            .iter()
+            .position(|b| *b == b',')
+            .map_or(buffer.len(), |len| start + len);
+        Some(start..end)
+    }
+
+    fn is_repeated(&mut self) -> bool {
+        let sentence = &self.buffer[..self.buflen];
+        let repeated = self.last_sentence_len == Some(self.buflen)
+            && &self.last_sentence[..self.buflen] == sentence;
+        self.last_sentence[..self.buflen].copy_from_slice(sentence);
+        self.last_sentence_len = Some(self.buflen);
+        repeated
+    }
+
+    fn read_byte(&mut self, symbol: u8) -> Option<Result<(), &'static str>> {
+        // Fast path for sentence data bytes which are the vast majority of input
+        if let ParserState::ReadUntilChkSum = self.parser_state {
+            if symbol != b'*' && symbol != b'\r' && symbol != b'\n' {
+                return self.store_byte(symbol);
+            }
+        }
         let (new_state, result) = match self.parser_state {
-            ParserState::WaitStart if symbol == b'$' => {
+            ParserState::WaitStart if symbol == b'$' || symbol == b'!' => {
                 self.buflen = 0;
                 self.chksum = 0;
+                self.encapsulated = symbol == b'!';
+                self.complete = false;
+                #[cfg(feature = "tag-blocks")]
+                {
+                    self.tag_block = self.pending_tag_block.take();
+                }
                 (ParserState::ReadUntilChkSum, None)
             }
-            ParserState::WaitStart if symbol != b'$' => (ParserState::WaitStart, None),
-            ParserState::ReadUntilChkSum if symbol != b'*' => {
-                if self.buffer.len() <= self.buflen {
+            #[cfg(feature = "tag-blocks")]
+            ParserState::WaitStart if symbol == b'\\' => {
+                self.tag_buflen = 0;
+                self.pending_tag_block = None;
+                (ParserState::ReadTagBlock, None)
+            }
+            #[cfg(feature = "tag-blocks")]
+            ParserState::ReadTagBlock if symbol == b'\\' => {
+                match TagBlock::parse(&self.tag_buffer[..self.tag_buflen]) {
+                    Ok(tag_block) => {
+                        self.pending_tag_block = Some(tag_block);
+                        (ParserState::WaitStart, None)
+                    }
+                    Err(e) => (ParserState::WaitStart, Some(Err(e))),
+                }
+            }
+            #[cfg(feature = "tag-blocks")]
+            ParserState::ReadTagBlock => {
+                if self.tag_buffer.len() <= self.tag_buflen {
                     (
-                        ParserState::WaitStart,
-                        Some(Err("NMEA sentence is too long!")),
+                        ParserState::SkipTagBlock,
+                        Some(Err("TAG block is too long!")),
                     )
                 } else {
-                    self.buffer[self.buflen] = symbol;
-                    self.buflen += 1;
-                    self.chksum = self.chksum ^ symbol;
-                    (ParserState::ReadUntilChkSum, None)
+                    self.tag_buffer[self.tag_buflen] = symbol;
+                    self.tag_buflen += 1;
+                    (ParserState::ReadTagBlock, None)
                 }
             }
+            // Rest of too long TAG block is dropped up to its end or line end
+            #[cfg(feature = "tag-blocks")]
+            ParserState::SkipTagBlock if symbol == b'\\' || symbol == b'\n' => {
+                (ParserState::WaitStart, None)
+            }
+            #[cfg(feature = "tag-blocks")]
+            ParserState::SkipTagBlock => (ParserState::SkipTagBlock, None),
+            ParserState::WaitStart => (ParserState::WaitStart, None),
+            ParserState::ReadUntilChkSum if symbol == b'\r' && self.allow_missing_checksum => {
+                (ParserState::WaitLF, None)
+            }
+            ParserState::ReadUntilChkSum
+                if symbol == b'\n' && self.allow_missing_checksum && self.accept_lf_only =>
+            {
+                (ParserState::WaitStart, Some(Ok(())))
+            }
+            ParserState::ReadUntilChkSum if symbol != b'*' => return self.store_byte(symbol),
             ParserState::ReadUntilChkSum if symbol == b'*' => (ParserState::ChkSumUpper, None),
             ParserState::ChkSumUpper => match parse_hex_halfbyte(symbol) {
                 Ok(s) => {
@@ -422,23 +1221,77 @@ impl Parser {
                 }
                 Err(e) => (ParserState::WaitStart, Some(Err(e))),
             },
-            ParserState::WaitCR if symbol == b'\r' => (ParserState::WaitLF, None),
-            ParserState::WaitLF if symbol == b'\n' => {
-                (ParserState::WaitStart, self.parse_sentence().transpose())
+            ParserState::WaitCR | ParserState::TrailingWhitespace if symbol == b'\r' => {
+                (ParserState::WaitLF, None)
+            }
+            ParserState::WaitCR | ParserState::TrailingWhitespace
+                if symbol == b'\n' && self.accept_lf_only =>
+            {
+                (ParserState::WaitStart, Some(Ok(())))
+            }
+            ParserState::WaitCR | ParserState::TrailingWhitespace
+                if (symbol == b' ' || symbol == b'\t') && self.tolerant_whitespace =>
+            {
+                (ParserState::TrailingWhitespace, None)
             }
-            _ => (ParserState::WaitStart, Some(Err("NMEA format error!"))),
+            ParserState::WaitLF if symbol == b'\n' => (ParserState::WaitStart, Some(Ok(()))),
+            ParserState::Resync if symbol == b'\n' => (ParserState::WaitStart, None),
+            ParserState::Resync => (ParserState::Resync, None),
+            _ => (self.error_state(symbol), Some(Err("NMEA format error!"))),
         };
         self.parser_state = new_state;
+        if let Some(Ok(())) = result {
+            self.complete = true;
+        }
         return result;
     }
 
+    fn store_byte(&mut self, symbol: u8) -> Option<Result<(), &'static str>> {
+        if symbol >= 128 {
+            self.parser_state = self.error_state(symbol);
+            return Some(Err("Not an ascii!"));
+        }
+        match self.buffer.get_mut(self.buflen) {
+            Some(slot) => {
+                *slot = symbol;
+                self.buflen += 1;
+                self.chksum ^= symbol;
+                None
+            }
+            None => {
+                self.parser_state = self.error_state(symbol);
+                Some(Err("NMEA sentence is too long!"))
+            }
+        }
+    }
+
+    fn error_state(&self, symbol: u8) -> ParserState {
+        if self.resync_at_line_end && symbol != b'\n' {
+            ParserState::Resync
+        } else {
+            ParserState::WaitStart
+        }
+    }
+
     fn parse_sentence(&self) -> Result<Option<ParseResult>, &'static str> {
-        let input = from_ascii(&self.buffer[..self.buflen])?;
+        if self.encapsulated {
+            return Err("Unsupported sentence type.");
+        }
+        // Buffer is checked to contain only ASCII while bytes are accumulated
+        let input = unsafe { core::str::from_utf8_unchecked(&self.buffer[..self.buflen]) };
+        self.parse_fields(input)
+    }
+
+    fn parse_fields(&self, input: &str) -> Result<Option<ParseResult>, &'static str> {
         let mut iter = input.split(',');
         let sentence_field = iter
             .next()
             .ok_or("Sentence type not found but mandatory!")?;
-        if sentence_field.len() < 5 {
+        #[cfg(feature = "ublox")]
+        let min_len = if sentence_field == "PUBX" { 4 } else { 5 };
+        #[cfg(not(feature = "ublox"))]
+        let min_len = 5;
+        if sentence_field.len() < min_len {
             return Err("Sentence field is too small. Must be 5 chars at least!");
         }
         let source = Source::try_from(sentence_field)?;
@@ -446,37 +1299,86 @@ impl Parser {
             return Ok(None);
         }
 
-        let sentence = match source {
-            #[cfg(feature = "mtk")]
-            Source::MTK => Sentence::try_from(&sentence_field[0..4])?,
-            _ => Sentence::try_from(&sentence_field[2..5])?,
-        };
+        let sentence = sentence_of(source, sentence_field)?;
 
         if self.sentence_mask.is_masked(sentence) {
             return Ok(None);
         }
         match sentence {
-            Sentence::RMC => Ok(Some(ParseResult::RMC(RMC::parse(source, &mut iter)?))),
-            Sentence::GGA => Ok(Some(ParseResult::GGA(GGA::parse(source, &mut iter)?))),
-            Sentence::GLL => Ok(Some(ParseResult::GLL(GLL::parse(source, &mut iter)?))),
-            Sentence::VTG => Ok(Some(ParseResult::VTG(VTG::parse(source, &mut iter)?))),
-            Sentence::GSV => Ok(Some(ParseResult::GSV(GSV::parse(source, &mut iter)?))),
-            Sentence::GSA => Ok(Some(ParseResult::GSA(GSA::parse(source, &mut iter)?))),
             #[cfg(feature = "mtk")]
             Sentence::PMTK => {
-                if sentence_field.len() < 7 {
-                    return Err("PMTK Sentence field is too small. Must be 7 chars at least!");
-                }
-                match MTKPacketType::try_from(&sentence_field[4..7])? {
+                let packet_type = sentence_field
+                    .get(4..7)
+                    .ok_or("PMTK Sentence field is too small. Must be 7 chars at least!")?;
+                match MTKPacketType::try_from(packet_type)? {
                     MTKPacketType::SPF => {
                         Ok(Some(ParseResult::PMTK(PMTKSPF::parse(source, &mut iter)?)))
                     }
                 }
             }
+            #[cfg(feature = "garmin")]
+            Sentence::PGRM => match GarminSentenceType::try_from(
+                sentence_field
+                    .get(4..)
+                    .ok_or("PGRM Sentence field is too small. Must be 5 chars at least!")?,
+            )? {
+                GarminSentenceType::E => {
+                    Ok(Some(ParseResult::PGRME(PGRME::parse(source, &mut iter)?)))
+                }
+                GarminSentenceType::Z => {
+                    Ok(Some(ParseResult::PGRMZ(PGRMZ::parse(source, &mut iter)?)))
+                }
+            },
+            _ => ParseResult::from_fields(source, sentence, &mut iter).map(Some),
+        }
+    }
+}
+
+fn is_clampable(error: &str) -> bool {
+    matches!(
+        error,
+        "Hours is not in range 0-23"
+            | "Minutes is not in range 0-59"
+            | "Seconds is not in range 0-59"
+    )
+}
+
+fn parse_digits(digits: &[u8]) -> Option<u32> {
+    digits.iter().try_fold(0u32, |value, digit| {
+        if digit.is_ascii_digit() {
+            Some(value * 10 + (digit - b'0') as u32)
+        } else {
+            None
+        }
+    })
+}
+
+/// Replaces decimal number in digits with max if it is greater. Returns true if replaced.
+fn clamp_number(digits: &mut [u8], max: u32) -> bool {
+    match parse_digits(digits) {
+        Some(value) if value > max => {
+            datetime::write_digits(digits, max as u64);
+            true
         }
+        _ => false,
     }
 }
 
+fn sentence_of(source: Source, sentence_field: &str) -> Result<Sentence, &'static str> {
+    let sentence_type = match source {
+        #[cfg(feature = "mtk")]
+        Source::MTK => sentence_field.get(0..4),
+        #[cfg(feature = "garmin")]
+        Source::Garmin => sentence_field.get(0..4),
+        #[cfg(feature = "ublox")]
+        Source::UBlox => Some(sentence_field),
+        _ => sentence_field.get(2..5),
+    }
+    .ok_or("Sentence field is too small. Must be 5 chars at least!")?;
+    Sentence::try_from(sentence_type)
+}
+
+#[cfg(feature = "tag-blocks")]
 fn from_ascii(bytes: &[u8]) -> Result<&str, &'static str> {
     if bytes.iter().all(|b| *b < 128) {
         Ok(unsafe { core::str::from_utf8_unchecked(bytes) })
@@ -486,13 +1388,81 @@ fn from_ascii(bytes: &[u8]) -> Result<&str, &'static str> {
 }
 
 fn parse_hex_halfbyte(symbol: u8) -> Result<u8, &'static str> {
-    if symbol >= b'0' && symbol <= b'9' {
-        return Ok(symbol - b'0');
+    match symbol {
+        b'0'..=b'9' => Ok(symbol - b'0'),
+        b'A'..=b'F' => Ok(symbol - b'A' + 10),
+        b'a'..=b'f' => Ok(symbol - b'a' + 10),
+        _ => Err("Invalid HEX character."),
     }
-    if symbol >= b'A' && symbol <= b'F' {
-        return Ok(symbol - b'A' + 10);
+}
+
+#[test]
+fn test_parse_hex_halfbyte() {
+    assert_eq!(parse_hex_halfbyte(b'0'), Ok(0));
+    assert_eq!(parse_hex_halfbyte(b'9'), Ok(9));
+    assert_eq!(parse_hex_halfbyte(b'A'), Ok(10));
+    assert_eq!(parse_hex_halfbyte(b'F'), Ok(15));
+    assert_eq!(parse_hex_halfbyte(b'a'), Ok(10));
+    assert_eq!(parse_hex_halfbyte(b'f'), Ok(15));
+    assert!(parse_hex_halfbyte(b'g').is_err());
+    assert!(parse_hex_halfbyte(b'G').is_err());
+}
+
+#[test]
+fn test_parse_source() {
+    assert_eq!(Source::try_from("GPRMC"), Ok(Source::GPS));
+    assert_eq!(
+        Source::try_from("IIVTG"),
+        Ok(Source::IntegratedInstrumentation)
+    );
+    assert_eq!(Source::try_from("ECRMC"), Ok(Source::ECDIS));
+    assert_eq!(Source::try_from("SDDPT"), Ok(Source::DepthSounder));
+    assert_eq!(Source::try_from("HCHDG"), Ok(Source::MagneticCompass));
+    assert_eq!(Source::try_from("WIMWV"), Ok(Source::WeatherInstruments));
+    assert_eq!(Source::try_from("VWVHW"), Ok(Source::WaterSpeed));
+    assert_eq!(Source::try_from("CRMSK"), Ok(Source::BeaconReceiver));
+    assert_eq!(Source::try_from("VDVBW"), Ok(Source::DopplerVelocity));
+    assert_eq!(Source::try_from("LCRMA"), Ok(Source::LoranC));
+    assert_eq!(Source::try_from("BDGSV"), Ok(Source::Beidou));
+    assert_eq!(Source::try_from("GBGSV"), Ok(Source::Beidou));
+    assert_eq!(Source::try_from("QZGSV"), Ok(Source::QZSS));
+    assert_eq!(Source::try_from("GIGSV"), Ok(Source::NavIC));
+    assert_eq!(Source::try_from("INGSV"), Ok(Source::NavIC));
+    assert!(Source::try_from("ZZRMC").is_err());
+    assert!(Source::try_from("G").is_err());
+    assert!(Source::try_from("").is_err());
+}
+
+#[test]
+fn test_source_talker_id() {
+    let sources = [
+        Source::GPS,
+        Source::GLONASS,
+        Source::Gallileo,
+        Source::Beidou,
+        Source::GNSS,
+        #[cfg(feature = "mtk")]
+        Source::MTK,
+        #[cfg(feature = "garmin")]
+        Source::Garmin,
+        #[cfg(feature = "ublox")]
+        Source::UBlox,
+        Source::IntegratedInstrumentation,
+        Source::ECDIS,
+        Source::DepthSounder,
+        Source::MagneticCompass,
+        Source::WeatherInstruments,
+        Source::WaterSpeed,
+        Source::QZSS,
+        Source::NavIC,
+        Source::BeaconReceiver,
+        Source::DopplerVelocity,
+        Source::LoranC,
+    ];
+    for source in sources.iter() {
+        assert_eq!(Source::try_from(source.talker_id()), Ok(*source));
     }
-    Err("Invalid HEX character.")
+    assert_eq!(Source::GLONASS.talker_id(), "GL");
 }
 
 #[test]
@@ -516,3 +1486,84 @@ fn test_create_filtered_parser() {
         .source_only(Source::GPS)
         .sentence_only(Sentence::RMC);
 }
+
+#[test]
+fn test_parser_with_filters() {
+    let mut parser =
+        Parser::with_filters(Source::GPS | Source::GLONASS, Sentence::RMC | Sentence::GLL);
+    assert!(parser.enabled_sources().contains(Source::GLONASS));
+    assert!(!parser.enabled_sources().contains(Source::Gallileo));
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n\
+$GAGLL,4916.45,N,12311.12,W,225444,A*20\r\n\
+$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
+    let mut iter = parser.parse_from_bytes(&b[..]);
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::GLL(Some(_))))));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_extract_time() {
+    let mut parser = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,030X2.454999,E,2,07,0.6,9.0,M,18.0,M,,*08\r\n";
+    assert!(parser.parse_from_bytes(&b[..]).next().unwrap().is_err());
+    assert_eq!(
+        parser.extract_time(),
+        Some(Time {
+            hours: 14,
+            minutes: 56,
+            seconds: 59.0
+        })
+    );
+    let b = b"$GPGLL,4916.45,N,123X1.12,W,225444,A*58\r\n";
+    assert!(parser.parse_from_bytes(&b[..]).next().unwrap().is_err());
+    assert_eq!(
+        parser.extract_time(),
+        Some(Time {
+            hours: 22,
+            minutes: 54,
+            seconds: 44.0
+        })
+    );
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n";
+    assert!(parser.parse_from_bytes(&b[..]).next().unwrap().is_ok());
+    assert_eq!(parser.extract_time(), None);
+}
+
+#[test]
+fn test_enabled_filters() {
+    let parser = Parser::new().sentence_only(Sentence::RMC);
+    assert!(parser.enabled_sentences().contains(Sentence::RMC));
+    assert!(!parser.enabled_sentences().contains(Sentence::GGA));
+    assert!(!parser.enabled_sentences().contains(Sentence::GSV));
+    assert!(parser.enabled_sources().contains(Source::GPS));
+    let parser = Parser::new().source_filter(Source::GPS | Source::GLONASS);
+    assert!(parser.enabled_sources().contains(Source::GLONASS));
+    assert!(!parser.enabled_sources().contains(Source::Gallileo));
+    assert!(parser.enabled_sentences().contains(Sentence::GSA));
+}
+
+#[test]
+fn test_mask_without() {
+    let sentences = SentenceMask::default().without(Sentence::GSV);
+    assert!(!sentences.contains(Sentence::GSV));
+    assert!(sentences.contains(Sentence::RMC));
+    assert!(sentences.contains(Sentence::GSA));
+    let sources = (Source::GPS | Source::GLONASS).without(Source::GPS);
+    assert!(!sources.contains(Source::GPS));
+    assert!(sources.contains(Source::GLONASS));
+
+    let mut parser = Parser::new().sentence_filter(sentences);
+    let b = b"$GPGSV,1,1,01,21,44,141,30*4C\r\n$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
+    let mut iter = parser.parse_from_bytes(&b[..]);
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::GLL(Some(_))))));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_const_parser() {
+    const PARSER: Parser = Parser::new();
+    let mut parser = PARSER;
+    let b = b"$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
+    assert_eq!(parser.parse_from_bytes(&b[..]).count(), 1);
+    let _parser: Parser = Default::default();
+}
@@ -14,8 +14,35 @@ impl Status {
     }
 }
 
-/// Receiver mode of operation.
+/// Navigational status (NMEA 4.1 and later).
 #[derive(Debug, PartialEq, Clone)]
+pub enum NavStatus {
+    /// Safe
+    Safe,
+    /// Caution
+    Caution,
+    /// Unsafe
+    Unsafe,
+    /// Navigational status is not valid, equipment is not providing navigational status indication.
+    NotValid,
+}
+
+impl NavStatus {
+    pub(crate) fn parse(input: Option<&str>) -> Result<Option<NavStatus>, &'static str> {
+        match input {
+            Some("S") => Ok(Some(NavStatus::Safe)),
+            Some("C") => Ok(Some(NavStatus::Caution)),
+            Some("U") => Ok(Some(NavStatus::Unsafe)),
+            Some("V") => Ok(Some(NavStatus::NotValid)),
+            Some("") => Ok(None),
+            None => Ok(None),
+            _ => Err("Wrong navigational status character!"),
+        }
+    }
+}
+
+/// Receiver mode of operation.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Mode {
     /// Autonomous mode without any external correction.
     Autonomous,
@@ -29,6 +56,12 @@ pub enum Mode {
     Simulator,
     /// Completely invalid state. Position data if present could not be used.
     NotValid,
+    /// Precise mode, no deliberate degradation like Selective Availability.
+    Precise,
+    /// Real time kinematic with fixed integer ambiguities.
+    RtkFixed,
+    /// Real time kinematic with floating ambiguities.
+    RtkFloat,
 }
 
 impl Mode {
@@ -37,9 +70,26 @@ impl Mode {
         match self {
             Mode::Autonomous => true,
             Mode::Differential => true,
+            Mode::Precise => true,
+            Mode::RtkFixed => true,
+            Mode::RtkFloat => true,
             _ => false,
         }
     }
+    /// NMEA character representing this mode.
+    pub fn as_char(&self) -> char {
+        match self {
+            Mode::Autonomous => 'A',
+            Mode::Differential => 'D',
+            Mode::Estimated => 'E',
+            Mode::Manual => 'M',
+            Mode::Simulator => 'S',
+            Mode::NotValid => 'N',
+            Mode::Precise => 'P',
+            Mode::RtkFixed => 'R',
+            Mode::RtkFloat => 'F',
+        }
+    }
 }
 
 impl Mode {
@@ -51,6 +101,9 @@ impl Mode {
             Some("M") => Ok(Mode::Manual),
             Some("S") => Ok(Mode::Simulator),
             Some("N") => Ok(Mode::NotValid),
+            Some("P") => Ok(Mode::Precise),
+            Some("R") => Ok(Mode::RtkFixed),
+            Some("F") => Ok(Mode::RtkFloat),
             None => Err("Mode field shoud not be null!"),
             Some("") => Err("Mode should not be empty string!"),
             _ => Err("Wrong mode character!"),
@@ -67,6 +120,9 @@ impl Mode {
             Some("M") => Ok(Mode::Manual),
             Some("S") => Ok(Mode::Simulator),
             Some("N") => Ok(Mode::NotValid),
+            Some("P") => Ok(Mode::Precise),
+            Some("R") => Ok(Mode::RtkFixed),
+            Some("F") => Ok(Mode::RtkFloat),
             None => match alternate {
                 Status::Valid => Ok(Mode::Autonomous),
                 Status::NotValid => Ok(Mode::NotValid),
@@ -84,6 +140,17 @@ fn test_parse_status() {
     assert_eq!(Status::from_str(""), Err("Invalid status field!"));
 }
 
+#[test]
+fn test_parse_nav_status() {
+    assert_eq!(NavStatus::parse(Some("S")), Ok(Some(NavStatus::Safe)));
+    assert_eq!(NavStatus::parse(Some("C")), Ok(Some(NavStatus::Caution)));
+    assert_eq!(NavStatus::parse(Some("U")), Ok(Some(NavStatus::Unsafe)));
+    assert_eq!(NavStatus::parse(Some("V")), Ok(Some(NavStatus::NotValid)));
+    assert_eq!(NavStatus::parse(Some("")), Ok(None));
+    assert_eq!(NavStatus::parse(None), Ok(None));
+    assert!(NavStatus::parse(Some("A")).is_err());
+}
+
 #[test]
 fn test_parse_mode() {
     assert_eq!(Mode::from_some_str(Some("A")), Ok(Mode::Autonomous));
@@ -92,11 +159,34 @@ fn test_parse_mode() {
     assert_eq!(Mode::from_some_str(Some("M")), Ok(Mode::Manual));
     assert_eq!(Mode::from_some_str(Some("S")), Ok(Mode::Simulator));
     assert_eq!(Mode::from_some_str(Some("N")), Ok(Mode::NotValid));
+    assert_eq!(Mode::from_some_str(Some("P")), Ok(Mode::Precise));
+    assert_eq!(Mode::from_some_str(Some("R")), Ok(Mode::RtkFixed));
+    assert_eq!(Mode::from_some_str(Some("F")), Ok(Mode::RtkFloat));
     assert!(Mode::from_some_str(None).is_err());
     assert!(Mode::from_some_str(Some("")).is_err());
     assert!(Mode::from_some_str(Some("abc")).is_err());
 }
 
+#[test]
+fn test_mode_as_char_roundtrip() {
+    let all = [
+        Mode::Autonomous,
+        Mode::Differential,
+        Mode::Estimated,
+        Mode::Manual,
+        Mode::Simulator,
+        Mode::NotValid,
+        Mode::Precise,
+        Mode::RtkFixed,
+        Mode::RtkFloat,
+    ];
+    for mode in all.iter() {
+        let mut buf = [0u8; 4];
+        let encoded = mode.as_char().encode_utf8(&mut buf);
+        assert_eq!(Mode::from_some_str(Some(encoded)), Ok(mode.clone()));
+    }
+}
+
 #[test]
 fn test_parse_mode_or_status() {
     assert_eq!(
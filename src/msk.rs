@@ -0,0 +1,64 @@
+use crate::common;
+use crate::Source;
+
+/// Control for a beacon receiver (MSK receiver interface).
+#[derive(Debug, PartialEq, Clone)]
+pub struct MSK {
+    /// Navigational system.
+    pub source: Source,
+    /// Beacon frequency in kHz.
+    pub frequency_khz: f32,
+    /// Frequency selection mode. `A` for auto, `M` for manual.
+    pub frequency_mode: char,
+    /// Beacon bit rate in bits per second.
+    pub bit_rate: u16,
+    /// Bit rate selection mode. `A` for auto, `M` for manual.
+    pub bit_rate_mode: char,
+    /// Interval in seconds for sending MSS signal status. None if status is not sent.
+    pub mss: Option<u16>,
+}
+
+impl MSK {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let frequency_khz = common::parse_f32(fields.next())?;
+        let frequency_mode = parse_selection_mode(fields.next())?;
+        let bit_rate = common::parse_u16(fields.next())?;
+        let bit_rate_mode = parse_selection_mode(fields.next())?;
+        let mss = common::parse_u16(fields.next())?;
+        if let (Some(frequency_khz), Some(frequency_mode), Some(bit_rate), Some(bit_rate_mode)) =
+            (frequency_khz, frequency_mode, bit_rate, bit_rate_mode)
+        {
+            Ok(Some(MSK {
+                source,
+                frequency_khz,
+                frequency_mode,
+                bit_rate,
+                bit_rate_mode,
+                mss,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn parse_selection_mode(input: Option<&str>) -> Result<Option<char>, &'static str> {
+    match input {
+        Some("A") => Ok(Some('A')),
+        Some("M") => Ok(Some('M')),
+        Some("") => Ok(None),
+        None => Ok(None),
+        _ => Err("Wrong MSK selection mode!"),
+    }
+}
+
+#[test]
+fn test_parse_selection_mode() {
+    assert_eq!(parse_selection_mode(Some("A")), Ok(Some('A')));
+    assert_eq!(parse_selection_mode(Some("M")), Ok(Some('M')));
+    assert_eq!(parse_selection_mode(Some("")), Ok(None));
+    assert!(parse_selection_mode(Some("X")).is_err());
+}
@@ -0,0 +1,43 @@
+use crate::common;
+use crate::coords::{Course, MagneticCourse, Speed};
+use crate::Source;
+
+/// Wind direction and speed.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MWD {
+    /// Navigational system.
+    pub source: Source,
+    /// Wind direction relative to true North.
+    pub direction_true: Option<Course>,
+    /// Wind direction relative to magnetic North.
+    pub direction_magnetic: Option<MagneticCourse>,
+    /// Wind speed. Taken from knots field if present, otherwise from meters per second field.
+    pub speed: Speed,
+}
+
+impl MWD {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let direction_true = Course::parse(fields.next())?;
+        fields.next(); // Not needed true direction marker field
+        let direction_magnetic = MagneticCourse::parse_from_str(fields.next())?;
+        fields.next(); // Not needed magnetic direction marker field
+        let speed_knots = Speed::parse(fields.next())?;
+        fields.next(); // Not needed speed knots marker field
+        let speed_mps = common::parse_f32(fields.next())?.map(Speed::from_mps);
+        fields.next(); // Not needed speed meters per second marker field
+
+        if let Some(speed) = speed_knots.or(speed_mps) {
+            Ok(Some(MWD {
+                source,
+                direction_true,
+                direction_magnetic,
+                speed,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::common;
+use crate::coords::{Course, Speed};
+use crate::Source;
+
+/// Own ship data. Heading, course, speed, set and drift of the vessel.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OSD {
+    /// Navigational system.
+    pub source: Source,
+    /// Heading relative to true North.
+    pub heading: Course,
+    /// Heading status. `A` for valid data, `V` for invalid.
+    pub heading_status: char,
+    /// Course relative to true North.
+    pub course_true: Option<Course>,
+    /// Course reference. `B` for bottom tracking log, `M` for manually entered, `W` for water referenced,
+    /// `R` for radar tracking of fixed target, `P` for positioning system ground reference.
+    pub course_reference: char,
+    /// Speed of the vessel.
+    pub speed: Speed,
+    /// Speed reference, same characters as for course reference.
+    pub speed_reference: char,
+    /// Direction of the current (set) relative to true North.
+    pub set: Option<Course>,
+    /// Speed of the current (drift).
+    pub drift: Speed,
+}
+
+impl OSD {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let heading = Course::parse(fields.next())?;
+        let heading_status = parse_heading_status(fields.next())?;
+        let course_true = Course::parse(fields.next())?;
+        let course_reference = parse_reference(fields.next())?;
+        let speed = common::parse_f32(fields.next())?;
+        let speed_reference = parse_reference(fields.next())?;
+        let set = Course::parse(fields.next())?;
+        let drift = common::parse_f32(fields.next())?;
+        let speed_unit = parse_speed_unit(fields.next())?;
+
+        if let (
+            Some(heading),
+            Some(heading_status),
+            Some(course_reference),
+            Some(speed),
+            Some(speed_reference),
+            Some(drift),
+            Some(speed_unit),
+        ) = (
+            heading,
+            heading_status,
+            course_reference,
+            speed,
+            speed_reference,
+            drift,
+            speed_unit,
+        ) {
+            Ok(Some(OSD {
+                source,
+                heading,
+                heading_status,
+                course_true,
+                course_reference,
+                speed: speed_unit(speed),
+                speed_reference,
+                set,
+                drift: speed_unit(drift),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn parse_heading_status(input: Option<&str>) -> Result<Option<char>, &'static str> {
+    match input {
+        Some("A") => Ok(Some('A')),
+        Some("V") => Ok(Some('V')),
+        Some("") | None => Ok(None),
+        _ => Err("Wrong OSD heading status!"),
+    }
+}
+
+fn parse_reference(input: Option<&str>) -> Result<Option<char>, &'static str> {
+    match input {
+        Some("B") => Ok(Some('B')),
+        Some("M") => Ok(Some('M')),
+        Some("W") => Ok(Some('W')),
+        Some("R") => Ok(Some('R')),
+        Some("P") => Ok(Some('P')),
+        Some("") | None => Ok(None),
+        _ => Err("Wrong OSD reference field!"),
+    }
+}
+
+type SpeedUnit = fn(f32) -> Speed;
+
+fn parse_speed_unit(input: Option<&str>) -> Result<Option<SpeedUnit>, &'static str> {
+    match input {
+        Some("N") => Ok(Some(Speed::from_knots)),
+        Some("K") => Ok(Some(Speed::from_kph)),
+        Some("S") => Ok(Some(Speed::from_mph)),
+        Some("") | None => Ok(None),
+        _ => Err("Wrong OSD speed units field!"),
+    }
+}
+
+#[test]
+fn test_parse_reference() {
+    assert_eq!(parse_reference(Some("B")), Ok(Some('B')));
+    assert_eq!(parse_reference(Some("P")), Ok(Some('P')));
+    assert_eq!(parse_reference(Some("")), Ok(None));
+    assert!(parse_reference(Some("X")).is_err());
+    assert_eq!(parse_heading_status(Some("V")), Ok(Some('V')));
+    assert!(parse_heading_status(Some("X")).is_err());
+    assert!(parse_speed_unit(Some("X")).is_err());
+}
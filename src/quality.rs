@@ -0,0 +1,23 @@
+//! Structures that describe quality of receiver's solution.
+
+/// Dilusion of precision values of the solution.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dop {
+    /// Position dilusion of precision. None if not reported, like in GGA.
+    pub pdop: Option<f32>,
+    /// Horizontal dilusion of precision.
+    pub hdop: f32,
+    /// Vertical dilusion of precision. None if not reported, like in GGA.
+    pub vdop: Option<f32>,
+}
+
+/// Estimated accuracy of the position in meters. Unifies accuracy reported by proprietary sentences.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Accuracy {
+    /// Horizontal position error.
+    pub horizontal: Option<f32>,
+    /// Vertical position error.
+    pub vertical: Option<f32>,
+    /// Overall spherical position error.
+    pub position: Option<f32>,
+}
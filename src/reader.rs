@@ -0,0 +1,78 @@
+use crate::{ParseResult, Parser};
+use std::io::{ErrorKind, Read};
+
+const READ_CHUNK_SIZE: usize = 256;
+
+/// Error of parsing NMEA from [Read](https://doc.rust-lang.org/std/io/trait.Read.html).
+#[derive(Debug)]
+pub enum ReaderError {
+    /// Reading from underlying reader failed.
+    Io(std::io::Error),
+    /// Sentence parsing error, same as returned by [parse_from_bytes](struct.Parser.html#method.parse_from_bytes).
+    Parse(&'static str),
+}
+
+impl core::fmt::Display for ReaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReaderError::Io(e) => write!(f, "{}", e),
+            ReaderError::Parse(e) => f.write_str(e),
+        }
+    }
+}
+
+impl std::error::Error for ReaderError {}
+
+pub(crate) struct ReaderIterator<'a, R> {
+    parser: &'a mut Parser,
+    reader: R,
+    buffer: [u8; READ_CHUNK_SIZE],
+    pos: usize,
+    len: usize,
+    finished: bool,
+}
+
+impl<R: Read> ReaderIterator<'_, R> {
+    pub(crate) fn new(p: &mut Parser, reader: R) -> ReaderIterator<'_, R> {
+        ReaderIterator {
+            parser: p,
+            reader,
+            buffer: [0; READ_CHUNK_SIZE],
+            pos: 0,
+            len: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for ReaderIterator<'_, R> {
+    type Item = Result<ParseResult, ReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        loop {
+            while self.pos < self.len {
+                let symbol = self.buffer[self.pos];
+                self.pos += 1;
+                if let Some(r) = self.parser.parse_from_byte(symbol) {
+                    return Some(r.map_err(ReaderError::Parse));
+                }
+            }
+            match self.reader.read(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(len) => {
+                    self.pos = 0;
+                    self.len = len;
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                // Reader is not retried after error, it may fail forever like closed device
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(ReaderError::Io(e)));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::common;
+use crate::coords::{Course, Hemisphere, Latitude, Longitude, MagneticCourse, Speed};
+use crate::modes::{Mode, Status};
+use crate::Source;
+
+/// Recommended minimum specific Loran-C data. Sentences with invalid status are returned as None.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RMA {
+    /// Navigational system.
+    pub source: Source,
+    /// Latitude.
+    pub latitude: Latitude,
+    /// Longitude.
+    pub longitude: Longitude,
+    /// Time difference A in microseconds.
+    pub time_difference_a: Option<f32>,
+    /// Time difference B in microseconds.
+    pub time_difference_b: Option<f32>,
+    /// Speed over ground.
+    pub speed: Speed,
+    /// Course over ground.
+    pub course: Course,
+    /// Magnetic variation in degrees as reported by receiver.
+    pub magnetic_variation: Option<f32>,
+    /// Magnetic variation direction. East or West.
+    pub magnetic_variation_dir: Option<Hemisphere>,
+    /// Receiver's mode of operation. Autonomous if not reported by pre NMEA 2.3 receivers.
+    pub mode: Mode,
+}
+
+impl RMA {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let status = if let Some(f_status) = fields.next() {
+            Status::from_str(f_status)?
+        } else {
+            return Err("Status field is mandatory for RMA sentence!");
+        };
+        let latitude = Latitude::parse(fields.next(), fields.next())?;
+        let longitude = Longitude::parse(fields.next(), fields.next())?;
+        let time_difference_a = common::parse_f32(fields.next())?;
+        let time_difference_b = common::parse_f32(fields.next())?;
+        let speed = Speed::parse(fields.next())?;
+        let course = Course::parse(fields.next())?;
+        let (magnetic_variation, magnetic_variation_dir) =
+            MagneticCourse::parse_variation(fields.next(), fields.next())?;
+        let mode = Mode::from_some_str_or_status(fields.next(), &status)?;
+
+        if let (Status::Valid, Some(latitude), Some(longitude), Some(speed), Some(course)) =
+            (status, latitude, longitude, speed, course)
+        {
+            Ok(Some(RMA {
+                source,
+                latitude,
+                longitude,
+                time_difference_a,
+                time_difference_b,
+                speed,
+                course,
+                magnetic_variation,
+                magnetic_variation_dir,
+                mode,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
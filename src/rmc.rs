@@ -1,6 +1,6 @@
-use crate::coords::{Course, Latitude, Longitude, MagneticCourse, Speed};
+use crate::coords::{Course, Hemisphere, Latitude, Longitude, MagneticCourse, Speed};
 use crate::datetime::{Date, DateTime, Time};
-use crate::modes::{Mode, Status};
+use crate::modes::{Mode, NavStatus, Status};
 use crate::Source;
 
 /// Recommended Minimum Sentence for any GNSS source.
@@ -20,8 +20,14 @@ pub struct RMC {
     pub course: Option<Course>,
     /// Magnetic course over ground (angle to magnetic North pole). Receiver may not report it.
     pub magnetic: Option<MagneticCourse>,
+    /// Magnetic variation in degrees as reported by receiver.
+    pub magnetic_variation: Option<f32>,
+    /// Magnetic variation direction. East or West.
+    pub magnetic_variation_dir: Option<Hemisphere>,
     /// Receiver's mode of operation.
     pub mode: Mode,
+    /// Navigational status. Reported since NMEA 4.1.
+    pub nav_status: Option<NavStatus>,
 }
 
 impl RMC {
@@ -40,8 +46,12 @@ impl RMC {
         let speed = Speed::parse(fields.next())?;
         let course = Course::parse(fields.next())?;
         let date = Date::parse_from_ddmmyy(fields.next())?;
-        let magnetic = MagneticCourse::parse_from_mvar_mdir(&course, fields.next(), fields.next())?;
+        let (mvar, mdir) = (fields.next(), fields.next());
+        let magnetic = MagneticCourse::parse_from_mvar_mdir(&course, mvar, mdir)?;
+        let (magnetic_variation, magnetic_variation_dir) =
+            MagneticCourse::parse_variation(mvar, mdir)?;
         let mode = Mode::from_some_str_or_status(fields.next(), &status)?;
+        let nav_status = NavStatus::parse(fields.next())?;
 
         let datetime = DateTime::from_date_and_time(date, time)?;
         if let (Some(datetime), Some(latitude), Some(longitude), Some(speed)) =
@@ -55,10 +65,24 @@ impl RMC {
                 speed,
                 course,
                 magnetic: magnetic,
+                magnetic_variation,
+                magnetic_variation_dir,
                 mode,
+                nav_status,
             }))
         } else {
             Ok(None)
         }
     }
+    /// True if speed is below the threshold, so course is most likely a noise.
+    pub fn is_stationary(&self, threshold: Speed) -> bool {
+        self.speed.as_knots() < threshold.as_knots()
+    }
+    #[cfg(feature = "math")]
+    /// Velocity over ground as north and east components in meters per second. None if course is not reported.
+    pub fn velocity_ned(&self) -> Option<(f32, f32)> {
+        let course = self.course.as_ref()?.degrees.to_radians();
+        let speed = self.speed.as_mps();
+        Some((speed * libm::cosf(course), speed * libm::sinf(course)))
+    }
 }
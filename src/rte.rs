@@ -0,0 +1,100 @@
+use crate::common;
+use crate::waypoint::WaypointId;
+use crate::Source;
+use crate::MAX_SENTENCE_LENGTH;
+
+/// Maximum number of waypoints stored from a single RTE sentence.
+pub const MAX_RTE_WAYPOINTS: usize = 10;
+
+/// Route definition. Long routes are split between several sentences.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RTE {
+    /// Navigational system.
+    pub source: Source,
+    /// Total number of sentences needed for full route.
+    pub total_messages: u8,
+    /// Number of this sentence.
+    pub message_number: u8,
+    /// Route type. `c` for complete route, `w` for working route where first waypoint is the one just left.
+    pub route_type: char,
+    /// Route identifier.
+    pub route_id: Option<WaypointId>,
+    // Waypoint names are packed into single buffer to keep ParseResult small
+    names: [u8; MAX_SENTENCE_LENGTH],
+    names_ends: [u8; MAX_RTE_WAYPOINTS],
+    waypoints_count: usize,
+}
+
+impl RTE {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let total_messages = common::parse_u8(fields.next())?;
+        let message_number = common::parse_u8(fields.next())?;
+        let route_type = parse_route_type(fields.next())?;
+        let route_id = WaypointId::parse(fields.next())?;
+        let mut names = [0u8; MAX_SENTENCE_LENGTH];
+        let mut names_ends = [0u8; MAX_RTE_WAYPOINTS];
+        let mut waypoints_count = 0;
+        let mut end = 0;
+        for field in fields {
+            if let Some(waypoint) = WaypointId::parse(Some(field))? {
+                if waypoints_count == MAX_RTE_WAYPOINTS {
+                    return Err("Too many waypoints in RTE sentence!");
+                }
+                let name = waypoint.as_str().as_bytes();
+                let name_buffer = names
+                    .get_mut(end..end + name.len())
+                    .ok_or("RTE waypoints are too long!")?;
+                name_buffer.copy_from_slice(name);
+                end += name.len();
+                names_ends[waypoints_count] = end as u8;
+                waypoints_count += 1;
+            }
+        }
+        if let (Some(total_messages), Some(message_number), Some(route_type)) =
+            (total_messages, message_number, route_type)
+        {
+            Ok(Some(RTE {
+                source,
+                total_messages,
+                message_number,
+                route_type,
+                route_id,
+                names,
+                names_ends,
+                waypoints_count,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Waypoint identifiers of this sentence in route order.
+    pub fn waypoints(&self) -> impl Iterator<Item = &str> {
+        let ends = &self.names_ends[..self.waypoints_count];
+        let starts = core::iter::once(0).chain(ends.iter().copied());
+        starts.zip(ends.iter()).map(move |(start, end)| {
+            // Constructed from &str only, so it is always valid UTF-8
+            core::str::from_utf8(&self.names[start as usize..*end as usize]).unwrap_or("")
+        })
+    }
+}
+
+fn parse_route_type(input: Option<&str>) -> Result<Option<char>, &'static str> {
+    match input {
+        Some("c") => Ok(Some('c')),
+        Some("w") => Ok(Some('w')),
+        Some("") => Ok(None),
+        None => Ok(None),
+        _ => Err("Wrong RTE route type!"),
+    }
+}
+
+#[test]
+fn test_parse_route_type() {
+    assert_eq!(parse_route_type(Some("c")), Ok(Some('c')));
+    assert_eq!(parse_route_type(Some("w")), Ok(Some('w')));
+    assert_eq!(parse_route_type(Some("")), Ok(None));
+    assert!(parse_route_type(Some("x")).is_err());
+}
@@ -1,6 +1,7 @@
 //! Structures that describe satellites in views .
 
 use crate::common;
+use crate::Source;
 
 ///Information about satellite in view.
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -23,6 +24,16 @@ impl Satellite {
         let elevation = common::parse_u8(fields.next())?;
         let azimuth = common::parse_u16(fields.next())?;
         let snr = common::parse_u8(fields.next())?;
+        if let Some(elevation) = elevation {
+            if elevation > 90 {
+                return Err("Satellite elevation should not exceed 90 degrees!");
+            }
+        }
+        if let Some(azimuth) = azimuth {
+            if azimuth >= 360 {
+                return Err("Satellite azimuth should be less than 360 degrees!");
+            }
+        }
 
         if let (Some(prn), Some(elevation), Some(azimuth)) = (prn, elevation, azimuth) {
             Ok(Some(Self {
@@ -35,4 +46,57 @@ impl Satellite {
             Ok(None)
         }
     }
+    /// Satellite is tracked by receiver if it has SNR reported.
+    pub fn is_tracked(&self) -> bool {
+        self.snr.is_some()
+    }
+    /// Satellite position is within allowed ranges and is not a zero elevation and azimuth placeholder.
+    pub fn is_valid_position(&self) -> bool {
+        self.elevation <= 90 && self.azimuth < 360 && !(self.elevation == 0 && self.azimuth == 0)
+    }
+    /// Constellation of satellite by its PRN number as reported in combined `GNGSV` sentences.
+    /// SBAS satellites augment GPS and are classified as GPS. Unknown PRNs are classified as GNSS.
+    pub fn classify_prn(prn: u16) -> Source {
+        match prn {
+            1..=32 => Source::GPS,
+            33..=64 => Source::GPS,
+            65..=96 => Source::GLONASS,
+            152..=158 => Source::GPS,
+            193..=199 => Source::QZSS,
+            201..=235 => Source::Beidou,
+            301..=336 => Source::Gallileo,
+            401..=437 => Source::Beidou,
+            _ => Source::GNSS,
+        }
+    }
+}
+
+#[test]
+fn test_parse_satellite() {
+    let tracked = Satellite::parse(&mut "14,79,048,42".split(','))
+        .unwrap()
+        .unwrap();
+    assert!(tracked.is_tracked());
+    assert!(tracked.is_valid_position());
+
+    let placeholder = Satellite::parse(&mut "26,00,000,".split(','))
+        .unwrap()
+        .unwrap();
+    assert!(!placeholder.is_tracked());
+    assert!(!placeholder.is_valid_position());
+
+    assert!(Satellite::parse(&mut "14,91,048,42".split(',')).is_err());
+    assert!(Satellite::parse(&mut "14,79,360,42".split(',')).is_err());
+}
+
+#[test]
+fn test_classify_prn() {
+    assert_eq!(Satellite::classify_prn(14), Source::GPS);
+    assert_eq!(Satellite::classify_prn(46), Source::GPS);
+    assert_eq!(Satellite::classify_prn(68), Source::GLONASS);
+    assert_eq!(Satellite::classify_prn(195), Source::QZSS);
+    assert_eq!(Satellite::classify_prn(210), Source::Beidou);
+    assert_eq!(Satellite::classify_prn(305), Source::Gallileo);
+    assert_eq!(Satellite::classify_prn(0), Source::GNSS);
+    assert_eq!(Satellite::classify_prn(120), Source::GNSS);
 }
@@ -0,0 +1,122 @@
+use crate::common;
+use crate::parse_hex_halfbyte;
+
+/// Maximum TAG block length between `\` delimiters according to NMEA 0183 4.x.
+pub(crate) const MAX_TAG_BLOCK_LENGTH: usize = 80;
+const MAX_TAG_SOURCE_LENGTH: usize = 15;
+
+/// Sentence grouping parsed from `g:` tag.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TagGroup {
+    /// Number of this sentence in the group.
+    pub sentence_number: u8,
+    /// Total number of sentences in the group.
+    pub total_sentences: u8,
+    /// Group identifier.
+    pub group_id: u16,
+}
+
+/// Checksum validated NMEA 4.x TAG block that precedes the sentence.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TagBlock {
+    source: [u8; MAX_TAG_SOURCE_LENGTH],
+    source_len: usize,
+    /// UNIX time in seconds parsed from `c:` tag.
+    pub timestamp: Option<u64>,
+    /// Sentence grouping parsed from `g:` tag.
+    pub group: Option<TagGroup>,
+}
+
+impl TagBlock {
+    pub(crate) fn parse(input: &[u8]) -> Result<TagBlock, &'static str> {
+        let star = input
+            .iter()
+            .rposition(|b| *b == b'*')
+            .ok_or("TAG block checksum not found!")?;
+        let (data, chksum) = (&input[..star], &input[star + 1..]);
+        if chksum.len() != 2 {
+            return Err("TAG block checksum not found!");
+        }
+        let expected = (parse_hex_halfbyte(chksum[0])? << 4) | parse_hex_halfbyte(chksum[1])?;
+        if data.iter().fold(0u8, |acc, b| acc ^ b) != expected {
+            return Err("TAG block checksum error!");
+        }
+        let data = crate::from_ascii(data)?;
+        let mut tag = TagBlock {
+            source: [0u8; MAX_TAG_SOURCE_LENGTH],
+            source_len: 0,
+            timestamp: None,
+            group: None,
+        };
+        for field in data.split(',') {
+            match field.get(..2) {
+                Some("s:") => {
+                    let source = &field.as_bytes()[2..];
+                    if source.len() > MAX_TAG_SOURCE_LENGTH {
+                        return Err("TAG block source is too long!");
+                    }
+                    tag.source[..source.len()].copy_from_slice(source);
+                    tag.source_len = source.len();
+                }
+                Some("c:") => {
+                    tag.timestamp = Some(
+                        field[2..]
+                            .parse()
+                            .map_err(|_| "Wrong TAG block timestamp format")?,
+                    );
+                }
+                Some("g:") => {
+                    let mut parts = field[2..].split('-');
+                    let sentence_number = common::parse_u8(parts.next())?;
+                    let total_sentences = common::parse_u8(parts.next())?;
+                    let group_id = common::parse_u16(parts.next())?;
+                    if let (Some(sentence_number), Some(total_sentences), Some(group_id)) =
+                        (sentence_number, total_sentences, group_id)
+                    {
+                        tag.group = Some(TagGroup {
+                            sentence_number,
+                            total_sentences,
+                            group_id,
+                        });
+                    } else {
+                        return Err("Wrong TAG block group format");
+                    }
+                }
+                _ => {} // Other tags are not supported yet
+            }
+        }
+        Ok(tag)
+    }
+    /// Source identifier parsed from `s:` tag.
+    pub fn source(&self) -> Option<&str> {
+        match self.source_len {
+            0 => None,
+            len => crate::from_ascii(&self.source[..len]).ok(),
+        }
+    }
+}
+
+#[test]
+fn test_parse_tag_block() {
+    let tag = TagBlock::parse(b"s:GPS,c:1503000000*7F").unwrap();
+    assert_eq!(tag.source(), Some("GPS"));
+    assert_eq!(tag.timestamp, Some(1503000000));
+    assert_eq!(tag.group, None);
+
+    let tag = TagBlock::parse(b"g:1-2-7387*55").unwrap();
+    assert_eq!(tag.source(), None);
+    assert_eq!(
+        tag.group,
+        Some(TagGroup {
+            sentence_number: 1,
+            total_sentences: 2,
+            group_id: 7387,
+        })
+    );
+
+    assert_eq!(
+        TagBlock::parse(b"s:GPS,c:1503000000*7E"),
+        Err("TAG block checksum error!")
+    );
+    assert!(TagBlock::parse(b"s:GPS").is_err());
+}
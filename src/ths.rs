@@ -0,0 +1,37 @@
+use crate::coords::Course;
+use crate::modes::Mode;
+use crate::Source;
+
+/// True heading and status.
+#[derive(Debug, PartialEq, Clone)]
+pub struct THS {
+    /// Navigational system.
+    pub source: Source,
+    /// Heading relative to true North.
+    pub heading: Course,
+    /// Heading sensor mode of operation.
+    pub status: Mode,
+}
+
+impl THS {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let heading = Course::parse(fields.next())?;
+        let status = match fields.next() {
+            Some("V") => Mode::NotValid,
+            status => Mode::from_some_str(status)?,
+        };
+
+        match (heading, status) {
+            (_, Mode::NotValid) => Ok(None),
+            (Some(heading), status) => Ok(Some(THS {
+                source,
+                heading,
+                status,
+            })),
+            (None, _) => Ok(None),
+        }
+    }
+}
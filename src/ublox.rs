@@ -0,0 +1,299 @@
+use crate::common;
+use crate::coords::{Course, Latitude, Longitude, Speed};
+use crate::datetime::{Date, Time};
+use crate::quality::Accuracy;
+use crate::Source;
+use core::convert::TryFrom;
+use core::time::Duration;
+
+/// U-blox PUBX message type. Unlike standard sentences it is numeric field right after PUBX.
+#[derive(Debug, PartialEq, Clone)]
+pub enum PUBXMessageType {
+    /// Position data (PUBX,00)
+    Position,
+    /// Time of day and clock information (PUBX,04)
+    Time,
+}
+
+impl TryFrom<&str> for PUBXMessageType {
+    type Error = &'static str;
+
+    fn try_from(from: &str) -> Result<Self, Self::Error> {
+        match from {
+            "00" => Ok(PUBXMessageType::Position),
+            "04" => Ok(PUBXMessageType::Time),
+            _ => Err("Unsupported PUBXMessageType."),
+        }
+    }
+}
+
+/// Navigation status reported in PUBX,00 message.
+#[derive(Debug, PartialEq, Clone)]
+pub enum PUBXNavStatus {
+    /// No fix
+    NoFix,
+    /// Dead reckoning only solution
+    DeadReckoning,
+    /// Stand alone 2D solution
+    StandAlone2D,
+    /// Stand alone 3D solution
+    StandAlone3D,
+    /// Differential 2D solution
+    Differential2D,
+    /// Differential 3D solution
+    Differential3D,
+    /// Combined GNSS and dead reckoning solution
+    Combined,
+    /// Time only solution
+    TimeOnly,
+}
+
+impl PUBXNavStatus {
+    pub(crate) fn parse(input: Option<&str>) -> Result<Option<PUBXNavStatus>, &'static str> {
+        match input {
+            Some("NF") => Ok(Some(PUBXNavStatus::NoFix)),
+            Some("DR") => Ok(Some(PUBXNavStatus::DeadReckoning)),
+            Some("G2") => Ok(Some(PUBXNavStatus::StandAlone2D)),
+            Some("G3") => Ok(Some(PUBXNavStatus::StandAlone3D)),
+            Some("D2") => Ok(Some(PUBXNavStatus::Differential2D)),
+            Some("D3") => Ok(Some(PUBXNavStatus::Differential3D)),
+            Some("RK") => Ok(Some(PUBXNavStatus::Combined)),
+            Some("TT") => Ok(Some(PUBXNavStatus::TimeOnly)),
+            Some("") => Ok(None),
+            None => Ok(None),
+            _ => Err("Wrong PUBXNavStatus indicator type!"),
+        }
+    }
+}
+
+/// U-blox proprietary position data message.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PUBX00 {
+    /// Navigational system.
+    pub source: Source,
+    /// Time of fix in UTC.
+    pub time: Time,
+    /// Latitude in reference datum, typically WGS-84.
+    pub latitude: Latitude,
+    /// Logitude in reference datum, typically WGS-84.
+    pub longitude: Longitude,
+    /// Altitude above user datum ellipsoid in meters.
+    pub altitude_ref: f32,
+    /// Navigation status.
+    pub nav_status: PUBXNavStatus,
+    /// Horizontal accuracy estimate in meters.
+    pub horizontal_accuracy: f32,
+    /// Vertical accuracy estimate in meters.
+    pub vertical_accuracy: f32,
+    /// Speed over ground.
+    pub speed: Speed,
+    /// Course over ground.
+    pub course: Option<Course>,
+    /// Vertical velocity in meters per second, positive downwards.
+    pub vertical_velocity: f32,
+    /// Age of differential corrections. None if DGPS not in use.
+    pub age_dgps: Option<Duration>,
+    /// Horizontal dilusion of precision.
+    pub hdop: f32,
+    /// Vertical dilusion of precision.
+    pub vdop: f32,
+    /// Time dilusion of precision.
+    pub tdop: f32,
+    /// Number of GPS satellites used in solution.
+    pub gps_sat_in_use: u8,
+    /// Number of GLONASS satellites used in solution.
+    pub glonass_sat_in_use: u8,
+    /// Dead reckoning is used.
+    pub dead_reckoning: bool,
+}
+
+impl PUBX00 {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let time = Time::parse_from_hhmmss(fields.next())?;
+        let latitude = Latitude::parse(fields.next(), fields.next())?;
+        let longitude = Longitude::parse(fields.next(), fields.next())?;
+        let altitude_ref = common::parse_f32(fields.next())?;
+        let nav_status = PUBXNavStatus::parse(fields.next())?;
+        let horizontal_accuracy = common::parse_f32(fields.next())?;
+        let vertical_accuracy = common::parse_f32(fields.next())?;
+        let speed = common::parse_f32(fields.next())?.map(Speed::from_kph);
+        let course = Course::parse(fields.next())?;
+        let vertical_velocity = common::parse_f32(fields.next())?;
+        let age_dgps = common::parse_duration(fields.next())?;
+        let hdop = common::parse_f32(fields.next())?;
+        let vdop = common::parse_f32(fields.next())?;
+        let tdop = common::parse_f32(fields.next())?;
+        let gps_sat_in_use = common::parse_u8(fields.next())?;
+        let glonass_sat_in_use = common::parse_u8(fields.next())?;
+        let dead_reckoning = common::parse_u8(fields.next())?;
+        if let (
+            Some(time),
+            Some(latitude),
+            Some(longitude),
+            Some(altitude_ref),
+            Some(nav_status),
+            Some(horizontal_accuracy),
+            Some(vertical_accuracy),
+            Some(speed),
+            Some(vertical_velocity),
+            Some(hdop),
+            Some(vdop),
+            Some(tdop),
+            Some(gps_sat_in_use),
+            Some(glonass_sat_in_use),
+            Some(dead_reckoning),
+        ) = (
+            time,
+            latitude,
+            longitude,
+            altitude_ref,
+            nav_status,
+            horizontal_accuracy,
+            vertical_accuracy,
+            speed,
+            vertical_velocity,
+            hdop,
+            vdop,
+            tdop,
+            gps_sat_in_use,
+            glonass_sat_in_use,
+            dead_reckoning,
+        ) {
+            Ok(Some(PUBX00 {
+                source,
+                time,
+                latitude,
+                longitude,
+                altitude_ref,
+                nav_status,
+                horizontal_accuracy,
+                vertical_accuracy,
+                speed,
+                course,
+                vertical_velocity,
+                age_dgps,
+                hdop,
+                vdop,
+                tdop,
+                gps_sat_in_use,
+                glonass_sat_in_use,
+                dead_reckoning: dead_reckoning != 0,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Accuracy estimates as unified accuracy. Overall position error is not reported by PUBX,00.
+    pub fn accuracy(&self) -> Accuracy {
+        Accuracy {
+            horizontal: Some(self.horizontal_accuracy),
+            vertical: Some(self.vertical_accuracy),
+            position: None,
+        }
+    }
+}
+
+/// U-blox proprietary time of day and clock information message.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PUBX04 {
+    /// Navigational system.
+    pub source: Source,
+    /// UTC time.
+    pub time: Time,
+    /// UTC date.
+    pub date: Date,
+    /// UTC time of week in seconds.
+    pub utc_tow: f32,
+    /// UTC week number.
+    pub week: u16,
+    /// Leap seconds between GPS and UTC time.
+    pub leap_seconds: u8,
+    /// Leap seconds value is firmware default, not received from satellites yet.
+    pub leap_seconds_default: bool,
+    /// Receiver clock bias in nanoseconds.
+    pub clock_bias_ns: f32,
+    /// Receiver clock drift in nanoseconds per second.
+    pub clock_drift_ns_s: f32,
+}
+
+impl PUBX04 {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let time = Time::parse_from_hhmmss(fields.next())?;
+        let date = Date::parse_from_ddmmyy(fields.next())?;
+        let utc_tow = common::parse_f32(fields.next())?;
+        let week = common::parse_u16(fields.next())?;
+        let (leap_seconds, leap_seconds_default) = match fields.next() {
+            Some(leap) if leap.ends_with('D') => {
+                (common::parse_u8(Some(&leap[..leap.len() - 1]))?, true)
+            }
+            leap => (common::parse_u8(leap)?, false),
+        };
+        let clock_bias_ns = common::parse_f32(fields.next())?;
+        let clock_drift_ns_s = common::parse_f32(fields.next())?;
+        if let (
+            Some(time),
+            Some(date),
+            Some(utc_tow),
+            Some(week),
+            Some(leap_seconds),
+            Some(clock_bias_ns),
+            Some(clock_drift_ns_s),
+        ) = (
+            time,
+            date,
+            utc_tow,
+            week,
+            leap_seconds,
+            clock_bias_ns,
+            clock_drift_ns_s,
+        ) {
+            Ok(Some(PUBX04 {
+                source,
+                time,
+                date,
+                utc_tow,
+                week,
+                leap_seconds,
+                leap_seconds_default,
+                clock_bias_ns,
+                clock_drift_ns_s,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[test]
+fn test_parse_pubx_message_type() {
+    assert_eq!(
+        PUBXMessageType::try_from("00"),
+        Ok(PUBXMessageType::Position)
+    );
+    assert_eq!(PUBXMessageType::try_from("04"), Ok(PUBXMessageType::Time));
+    assert!(PUBXMessageType::try_from("99").is_err());
+}
+
+#[test]
+fn test_parse_pubx_nav_status() {
+    assert_eq!(
+        PUBXNavStatus::parse(Some("NF")),
+        Ok(Some(PUBXNavStatus::NoFix))
+    );
+    assert_eq!(
+        PUBXNavStatus::parse(Some("G3")),
+        Ok(Some(PUBXNavStatus::StandAlone3D))
+    );
+    assert_eq!(
+        PUBXNavStatus::parse(Some("TT")),
+        Ok(Some(PUBXNavStatus::TimeOnly))
+    );
+    assert_eq!(PUBXNavStatus::parse(Some("")), Ok(None));
+    assert!(PUBXNavStatus::parse(Some("XX")).is_err());
+}
@@ -0,0 +1,69 @@
+use crate::common;
+use crate::modes::Status;
+use crate::Source;
+
+/// Dual ground and water speed.
+#[derive(Debug, PartialEq, Clone)]
+pub struct VBW {
+    /// Navigational system.
+    pub source: Source,
+    /// Longitudinal water speed in knots, negative astern.
+    pub longitudinal_water: f32,
+    /// Transverse water speed in knots, negative to port.
+    pub transverse_water: f32,
+    /// Water speed data is valid.
+    pub water_valid: bool,
+    /// Longitudinal ground speed in knots, negative astern.
+    pub longitudinal_ground: f32,
+    /// Transverse ground speed in knots, negative to port.
+    pub transverse_ground: f32,
+    /// Ground speed data is valid.
+    pub ground_valid: bool,
+}
+
+impl VBW {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let longitudinal_water = common::parse_f32(fields.next())?;
+        let transverse_water = common::parse_f32(fields.next())?;
+        let water_valid = parse_status(fields.next())?;
+        let longitudinal_ground = common::parse_f32(fields.next())?;
+        let transverse_ground = common::parse_f32(fields.next())?;
+        let ground_valid = parse_status(fields.next())?;
+
+        if let (
+            Some(longitudinal_water),
+            Some(transverse_water),
+            Some(longitudinal_ground),
+            Some(transverse_ground),
+        ) = (
+            longitudinal_water,
+            transverse_water,
+            longitudinal_ground,
+            transverse_ground,
+        ) {
+            Ok(Some(VBW {
+                source,
+                longitudinal_water,
+                transverse_water,
+                water_valid,
+                longitudinal_ground,
+                transverse_ground,
+                ground_valid,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn parse_status(input: Option<&str>) -> Result<bool, &'static str> {
+    match input {
+        // Empty status is reported along with empty speeds when there is no data
+        Some("") => Ok(false),
+        Some(status) => Ok(Status::from_str(status)? == Status::Valid),
+        None => Err("Status field is mandatory for VBW sentence!"),
+    }
+}
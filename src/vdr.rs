@@ -0,0 +1,40 @@
+use crate::coords::{Course, MagneticCourse, Speed};
+use crate::Source;
+
+/// Set and drift, direction and speed of the current.
+#[derive(Debug, PartialEq, Clone)]
+pub struct VDR {
+    /// Navigational system.
+    pub source: Source,
+    /// Direction of the current (set) relative to true North.
+    pub direction_true: Course,
+    /// Direction of the current (set) relative to magnetic North.
+    pub direction_magnetic: Option<MagneticCourse>,
+    /// Speed of the current (drift).
+    pub speed: Speed,
+}
+
+impl VDR {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let direction_true = Course::parse(fields.next())?;
+        fields.next(); // Not needed true direction marker field
+        let direction_magnetic = MagneticCourse::parse_from_str(fields.next())?;
+        fields.next(); // Not needed magnetic direction marker field
+        let speed = Speed::parse(fields.next())?;
+        fields.next(); // Not needed speed knots marker field
+
+        if let (Some(direction_true), Some(speed)) = (direction_true, speed) {
+            Ok(Some(VDR {
+                source,
+                direction_true,
+                direction_magnetic,
+                speed,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
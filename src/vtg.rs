@@ -1,5 +1,6 @@
+use crate::common;
 use crate::coords::{Course, MagneticCourse, Speed};
-use crate::modes::Mode;
+use crate::modes::{Mode, Status};
 use crate::Source;
 
 /// The actual course and speed relative to the ground.
@@ -13,7 +14,7 @@ pub struct VTG {
     pub magnetic: Option<MagneticCourse>,
     /// Speed over ground.
     pub speed: Speed,
-    /// Receiver's mode of operation.
+    /// Receiver's mode of operation. Autonomous if not reported by pre NMEA 2.3 receivers.
     pub mode: Mode,
 }
 
@@ -26,11 +27,15 @@ impl VTG {
         fields.next(); // Not needed true course marker field
         let magnetic = MagneticCourse::parse_from_str(fields.next())?;
         fields.next(); // Not needed magnetic course marker field
-        let speed = Speed::parse(fields.next())?;
+        let speed_knots = Speed::parse(fields.next())?;
         fields.next(); // Not needed speed knots marker field
-        fields.next(); // Not needed speed kph field
+        let speed_kph = common::parse_f32(fields.next())?.map(Speed::from_kph);
         fields.next(); // Not needed speed kph marker field
-        let mode = Mode::from_some_str(fields.next())?;
+
+        // Some receivers report speed in km/h only
+        let speed = speed_knots.or(speed_kph);
+        // Mode field is absent before NMEA 2.3, such VTG is treated as autonomous
+        let mode = Mode::from_some_str_or_status(fields.next(), &Status::Valid)?;
 
         if let Some(speed) = speed {
             Ok(Some(VTG {
@@ -44,4 +49,14 @@ impl VTG {
             Ok(None)
         }
     }
+    /// Magnetic variation in degrees derived from true and magnetic courses, in range -180 to 180.
+    /// Positive value means East variation. None if any of courses is not reported.
+    pub fn magnetic_variation(&self) -> Option<f32> {
+        let magnetic = Course::from(self.magnetic.as_ref()?.degrees());
+        Some(magnetic.shortest_diff(self.course.as_ref()?))
+    }
+    /// True if speed is below the threshold, so course is most likely a noise.
+    pub fn is_stationary(&self, threshold: Speed) -> bool {
+        self.speed.as_knots() < threshold.as_knots()
+    }
 }
@@ -0,0 +1,42 @@
+/// Maximum supported waypoint identifier length.
+pub const MAX_WAYPOINT_ID_LENGTH: usize = 16;
+
+/// Waypoint identifier stored in fixed buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct WaypointId {
+    id: [u8; MAX_WAYPOINT_ID_LENGTH],
+    len: usize,
+}
+
+impl WaypointId {
+    pub(crate) fn parse(input: Option<&str>) -> Result<Option<Self>, &'static str> {
+        match input {
+            Some("") | None => Ok(None),
+            Some(id) if id.len() > MAX_WAYPOINT_ID_LENGTH => Err("Waypoint ID is too long!"),
+            Some(id) => {
+                let mut waypoint = WaypointId {
+                    id: [0u8; MAX_WAYPOINT_ID_LENGTH],
+                    len: id.len(),
+                };
+                waypoint.id[..id.len()].copy_from_slice(id.as_bytes());
+                Ok(Some(waypoint))
+            }
+        }
+    }
+    /// Waypoint identifier as string.
+    pub fn as_str(&self) -> &str {
+        // Constructed from &str only, so it is always valid UTF-8
+        core::str::from_utf8(&self.id[..self.len]).unwrap_or("")
+    }
+}
+
+#[test]
+fn test_parse_waypoint_id() {
+    assert_eq!(
+        WaypointId::parse(Some("EGLM")).unwrap().unwrap().as_str(),
+        "EGLM"
+    );
+    assert_eq!(WaypointId::parse(Some("")), Ok(None));
+    assert_eq!(WaypointId::parse(None), Ok(None));
+    assert!(WaypointId::parse(Some("WAYPOINT_ID_TOO_LONG")).is_err());
+}
@@ -1,20 +1,41 @@
 use core::convert::TryFrom;
+use core::time::Duration;
 use nmea0183::coords;
 use nmea0183::coords::Hemisphere;
 use nmea0183::coords::Latitude;
 use nmea0183::coords::Longitude;
+use nmea0183::cross_check_fix;
 use nmea0183::datetime;
+use nmea0183::quality;
 use nmea0183::satellite;
+use nmea0183::Fix;
+use nmea0183::FixAggregator;
+use nmea0183::FixStats;
 use nmea0183::FixType;
 use nmea0183::GPSQuality;
+use nmea0183::GsaCollector;
+use nmea0183::GsvCollector;
+#[cfg(feature = "mtk")]
 use nmea0183::JammingStatus;
+use nmea0183::LatestCache;
 use nmea0183::Mode;
+use nmea0183::NavStatus;
 use nmea0183::GGA;
 use nmea0183::GLL;
+use nmea0183::MSK;
+#[cfg(feature = "garmin")]
+use nmea0183::PGRME;
+#[cfg(feature = "garmin")]
+use nmea0183::PGRMZ;
+#[cfg(feature = "mtk")]
 use nmea0183::PMTKSPF;
 use nmea0183::RMC;
+use nmea0183::THS;
+use nmea0183::VBW;
 use nmea0183::VTG;
-use nmea0183::{ParseResult, Parser, Source};
+#[cfg(all(feature = "ublox", not(feature = "strict")))]
+use nmea0183::{PUBXNavStatus, PUBX00};
+use nmea0183::{ParseResult, Parser, Sentence, Source};
 
 #[test]
 #[cfg(feature = "strict")]
@@ -52,10 +73,453 @@ fn test_too_long_sentence_non_strict() {
     assert!(caught_error);
 }
 
+#[test]
+fn test_non_ascii_byte_rejected() {
+    let b = b"$GPGLL,4916.45,N,\xC012311.12,W,225444,A*31\r\n\
+$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
+    let mut p = Parser::new();
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert_eq!(iter.next(), Some(Err("Not an ascii!")));
+    match iter.next() {
+        Some(Ok(ParseResult::GLL(Some(_)))) => {}
+        _ => panic!("Unexpected ParseResult variant while parsing GLL data."),
+    }
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_resync_at_line_end() {
+    let mut line = [b'A'; 160];
+    line[0] = b'$';
+    let embedded = b"$GPVTG,089.0,T,,,15.2,N,,,A*12";
+    line[130..].copy_from_slice(&embedded[..]);
+    let clean = b"\r\n$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
+
+    // Embedded sentence is parsed from corrupted data without resync
+    let mut p = Parser::new();
+    {
+        let mut results = p.parse_from_bytes(&line[..]);
+        assert_eq!(results.next(), Some(Err("NMEA sentence is too long!")));
+        assert!(results.next().is_none());
+    }
+    assert!(matches!(
+        p.parse_from_bytes(&clean[..]).next(),
+        Some(Ok(ParseResult::VTG(Some(_))))
+    ));
+
+    let mut p = Parser::new().resync_at_line_end(true);
+    {
+        let mut results = p.parse_from_bytes(&line[..]);
+        assert_eq!(results.next(), Some(Err("NMEA sentence is too long!")));
+        assert!(results.next().is_none());
+    }
+    let mut results = p.parse_from_bytes(&clean[..]);
+    assert!(matches!(
+        results.next(),
+        Some(Ok(ParseResult::GLL(Some(_))))
+    ));
+    assert!(results.next().is_none());
+
+    // Sentence with non-ASCII byte is skipped up to line end too
+    let b = b"$GPGLL,49\xff16.45$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
+    let mut p = Parser::new().resync_at_line_end(true);
+    let mut results = p.parse_from_bytes(&b[..]);
+    assert_eq!(results.next(), Some(Err("Not an ascii!")));
+    assert!(matches!(
+        results.next(),
+        Some(Ok(ParseResult::GLL(Some(_))))
+    ));
+    assert!(results.next().is_none());
+}
+
+#[test]
+fn test_speed_display() {
+    let speed = coords::Speed::from_knots(15.2);
+    assert_eq!(format!("{}", speed), "15.2 kn");
+    assert_eq!(format!("{}", speed.in_knots()), "15.2 kn");
+    assert_eq!(format!("{:.1}", speed.in_kph()), "28.2 km/h");
+    assert_eq!(format!("{:.1}", speed.in_mph()), "17.5 mph");
+    assert_eq!(format!("{:.2}", speed.in_mps()), "7.82 m/s");
+}
+
+#[test]
+fn test_truncated_sentence_field() {
+    let mut p = Parser::new();
+    let b = b"$GPR*45\r\n$GPRM*08\r\n$GPRMC*4B\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert_eq!(
+        iter.next(),
+        Some(Err(
+            "Sentence field is too small. Must be 5 chars at least!"
+        ))
+    );
+    assert_eq!(
+        iter.next(),
+        Some(Err(
+            "Sentence field is too small. Must be 5 chars at least!"
+        ))
+    );
+    assert_eq!(
+        iter.next(),
+        Some(Err("Status field is mandatory for RMC sentence!"))
+    );
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_encapsulated_sentence_before_rmc() {
+    let mut p = Parser::new();
+    let b = b"!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n\
+$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n";
+    {
+        let mut iter = p.parse_from_bytes(&b[..]);
+        assert_eq!(iter.next(), Some(Err("Unsupported sentence type.")));
+        assert!(matches!(iter.next(), Some(Ok(ParseResult::RMC(Some(_))))));
+        assert_eq!(iter.next(), None);
+    }
+
+    let mut raw = p.raw_sentences(&b[..]);
+    assert!(raw.next().unwrap().unwrap().is_encapsulated());
+    assert!(!raw.next().unwrap().unwrap().is_encapsulated());
+}
+
+#[cfg(feature = "tag-blocks")]
+#[test]
+fn test_tag_block_before_rmc() {
+    let mut p = Parser::new();
+    let b = b"\\s:GPS,c:1503000000*7F\\$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n";
+    let mut parsed = false;
+    for b in b.iter() {
+        if let Some(result) = p.parse_from_byte(*b) {
+            assert!(matches!(result, Ok(ParseResult::RMC(Some(_)))));
+            let tag = p.tag_block().unwrap();
+            assert_eq!(tag.source(), Some("GPS"));
+            assert_eq!(tag.timestamp, Some(1503000000));
+            parsed = true;
+        }
+    }
+    assert!(parsed);
+
+    let b = b"\\s:GPS,c:1503000000*7E\\$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert_eq!(iter.next(), Some(Err("TAG block checksum error!")));
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::RMC(Some(_))))));
+    assert_eq!(iter.next(), None);
+
+    let mut p = Parser::new();
+    let mut b = Vec::new();
+    b.push(b'\\');
+    b.extend_from_slice(&[b'a'; 100]);
+    b.extend_from_slice(
+        b"\\$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n",
+    );
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert_eq!(iter.next(), Some(Err("TAG block is too long!")));
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::RMC(Some(_))))));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_value_types_as_keys() {
+    use std::collections::HashSet;
+    let mut modes = HashSet::new();
+    modes.insert(Mode::Autonomous);
+    modes.insert(Mode::Differential);
+    modes.insert(Mode::Autonomous);
+    assert_eq!(modes.len(), 2);
+    let mut qualities = HashSet::new();
+    qualities.insert(GPSQuality::GPS);
+    qualities.insert(GPSQuality::DGPS);
+    assert!(qualities.contains(&GPSQuality::DGPS));
+    let mut keys = HashSet::new();
+    keys.insert((FixType::Fix3D, Hemisphere::North));
+    keys.insert((FixType::Fix3D, Hemisphere::North));
+    keys.insert((FixType::Fix2D, Hemisphere::South));
+    assert_eq!(keys.len(), 2);
+    let date = datetime::Date {
+        day: 20,
+        month: 9,
+        year: 2006,
+    };
+    let copied = date;
+    let mut dates = HashSet::new();
+    dates.insert(date);
+    assert!(dates.contains(&copied));
+}
+
+#[test]
+fn test_skip_empty() {
+    let b = b"$GPGGA,,,,,,,,,,,,,,*56\r\n$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n";
+    let mut p = Parser::new();
+    {
+        let mut iter = p.parse_from_bytes(&b[..]);
+        assert_eq!(iter.next(), Some(Ok(ParseResult::GGA(None))));
+        assert!(matches!(iter.next(), Some(Ok(ParseResult::RMC(Some(_))))));
+    }
+    let mut p = Parser::new().skip_empty(true);
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::RMC(Some(_))))));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_has_valid_fix() {
+    let mut p = Parser::new();
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,0,07,0.6,9.0,M,18.0,M,,*60\r\n\
+$GPGLL,,,,,,V,N*64\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    let rmc = iter.next().unwrap().unwrap();
+    assert!(matches!(rmc, ParseResult::RMC(Some(_))));
+    assert!(rmc.has_valid_fix());
+    let gga = iter.next().unwrap().unwrap();
+    assert!(matches!(gga, ParseResult::GGA(Some(_))));
+    assert!(!gga.has_valid_fix());
+    let gll = iter.next().unwrap().unwrap();
+    assert_eq!(gll, ParseResult::GLL(None));
+    assert!(!gll.has_valid_fix());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_parse_reader() {
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*00\r\n";
+    let mut p = Parser::new();
+    let results: Vec<_> = p.parse_reader(&b[..]).collect();
+    assert_eq!(results.len(), 3);
+    assert!(matches!(results[0], Ok(ParseResult::RMC(Some(_)))));
+    assert!(matches!(results[1], Ok(ParseResult::GGA(Some(_)))));
+    assert!(matches!(
+        results[2],
+        Err(nmea0183::ReaderError::Parse("Checksum error!"))
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_parse_reader_io_error() {
+    struct FailingReader;
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+    }
+    let mut p = Parser::new();
+    let mut iter = p.parse_reader(FailingReader);
+    assert!(matches!(
+        iter.next(),
+        Some(Err(nmea0183::ReaderError::Io(_)))
+    ));
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_parse_result_time() {
+    let mut p = Parser::new();
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n\
+$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    let rmc = iter.next().unwrap().unwrap();
+    assert_eq!(
+        rmc.time(),
+        Some(&datetime::Time {
+            hours: 12,
+            minutes: 55,
+            seconds: 4.049
+        })
+    );
+    let gga = iter.next().unwrap().unwrap();
+    assert_eq!(
+        gga.time(),
+        Some(&datetime::Time {
+            hours: 14,
+            minutes: 56,
+            seconds: 59.0
+        })
+    );
+    let vtg = iter.next().unwrap().unwrap();
+    assert!(matches!(vtg, ParseResult::VTG(Some(_))));
+    assert_eq!(vtg.time(), None);
+}
+
+#[test]
+fn test_buffered_len() {
+    let mut p = Parser::new();
+    assert_eq!(p.buffered_len(), 0);
+    assert_eq!(p.state_name(), "Waiting for sentence start");
+    assert_eq!(p.parse_from_bytes(&b"$GPVTG,089.0"[..]).next(), None);
+    assert_eq!(p.buffered_len(), 11);
+    assert_eq!(p.state_name(), "Reading sentence");
+    assert_eq!(p.parse_from_bytes(&b",T,,,15.2,N,,,A*1"[..]).next(), None);
+    assert_eq!(p.buffered_len(), 26);
+    assert_eq!(p.state_name(), "Reading checksum");
+    assert!(matches!(
+        p.parse_from_bytes(&b"2\r\n"[..]).next(),
+        Some(Ok(ParseResult::VTG(Some(_))))
+    ));
+    assert_eq!(p.buffered_len(), 0);
+    assert!(p.is_synced());
+}
+
+#[test]
+fn test_clamp_out_of_range() {
+    let b = b"$GPGGA,236100.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*6E\r\n";
+    let mut p = Parser::new();
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next(),
+        Some(Err("Minutes is not in range 0-59"))
+    );
+
+    let mut p = Parser::new().clamp_out_of_range(true);
+    match p.parse_from_bytes(&b[..]).next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(
+                gga.time,
+                datetime::Time {
+                    hours: 23,
+                    minutes: 59,
+                    seconds: 59.0
+                }
+            );
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+    assert!(p.clamped());
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n";
+    assert!(p.parse_from_bytes(&b[..]).next().unwrap().is_ok());
+    assert!(!p.clamped());
+}
+
+#[test]
+fn test_flush() {
+    let mut p = Parser::new();
+    assert_eq!(p.flush(), None);
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\r";
+    assert_eq!(p.parse_from_bytes(&b[..]).next(), None);
+    assert!(matches!(p.flush(), Some(Ok(ParseResult::VTG(Some(_))))));
+    assert!(p.is_synced());
+    assert_eq!(p.flush(), None);
+
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12";
+    assert_eq!(p.parse_from_bytes(&b[..]).next(), None);
+    assert!(matches!(p.flush(), Some(Ok(ParseResult::VTG(Some(_))))));
+
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*1";
+    assert_eq!(p.parse_from_bytes(&b[..]).next(), None);
+    assert_eq!(p.flush(), None);
+    assert!(matches!(
+        p.parse_from_bytes(&b"2\r\n"[..]).next(),
+        Some(Ok(ParseResult::VTG(Some(_))))
+    ));
+}
+
+#[test]
+fn test_dedup() {
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n\
+$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n\
+$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n\
+$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n";
+    let mut p = Parser::new();
+    assert_eq!(p.parse_from_bytes(&b[..]).count(), 4);
+    let mut p = Parser::new().dedup(true);
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::RMC(Some(_))))));
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::VTG(Some(_))))));
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::RMC(Some(_))))));
+    assert_eq!(iter.next(), None);
+
+    // Same checksum and length but different content
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n$GPVTG,098.0,T,,,15.2,N,,,A*12\r\n";
+    let mut p = Parser::new().dedup(true);
+    assert_eq!(p.parse_from_bytes(&b[..]).count(), 2);
+
+    // Error or filtered sentence between identical sentences
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n$GPVTG,089.0,T,,,15.2,N,,,A*13\r\n\
+$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n$GLVTG,089.0,T,,,15.2,N,,,A*0E\r\n\
+$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n";
+    let mut p = Parser::new().dedup(true).source_only(Source::GPS);
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::VTG(Some(_))))));
+    assert_eq!(iter.next(), Some(Err("Checksum error!")));
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::VTG(Some(_))))));
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::VTG(Some(_))))));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_reject_null_island() {
+    let b = b"$GPGGA,145659.00,0000.0000,N,00000.0000,E,1,03,3.6,,M,,M,,*50\r\n";
+    let mut p = Parser::new();
+    match p.parse_from_bytes(&b[..]).next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => assert_eq!(gga.latitude.as_f64(), 0f64),
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+    let mut p = Parser::new().reject_null_island(true);
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next(),
+        Some(Ok(ParseResult::GGA(None)))
+    );
+}
+
+#[cfg(feature = "math")]
+#[test]
+fn test_rmc_velocity_ned() {
+    let mut p = Parser::new();
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,19.4384,90.0,200906,,,A*57\r\n\
+$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,,200906,,,A*75\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::RMC(Some(rmc)))) => {
+            let (north, east) = rmc.velocity_ned().unwrap();
+            assert!(north.abs() < 0.001);
+            assert!((east - 10.0).abs() < 0.001);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing RMC data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::RMC(Some(rmc)))) => assert_eq!(rmc.velocity_ned(), None),
+        _ => panic!("Unexpected ParseResult variant while parsing RMC data."),
+    }
+}
+
+#[test]
+fn test_gll_without_status() {
+    let mut p = Parser::new();
+    let b = b"$GPGLL,4916.45,N,12311.12,W,225444*5C\r\n";
+    let result = p.parse_from_bytes(&b[..]).next().unwrap();
+    #[cfg(feature = "legacy")]
+    match result {
+        Ok(ParseResult::GLL(Some(gll))) => assert_eq!(gll.mode, Mode::Autonomous),
+        _ => panic!("Unexpected ParseResult variant while parsing GLL data."),
+    }
+    #[cfg(not(feature = "legacy"))]
+    assert_eq!(result, Err("Status field is mandatory for GLL sentence!"));
+}
+
+#[test]
+fn test_is_synced() {
+    let mut p = Parser::new();
+    assert!(p.is_synced());
+    let b = b"\xEF\xBB\xBFnoise$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
+    assert_eq!(p.parse_from_bytes(&b[..8]).count(), 0);
+    assert!(p.is_synced());
+    assert_eq!(p.parse_from_bytes(&b[8..20]).count(), 0);
+    assert!(!p.is_synced());
+    let mut iter = p.parse_from_bytes(&b[20..]);
+    assert!(matches!(iter.next(), Some(Ok(ParseResult::GLL(Some(_))))));
+    assert!(iter.next().is_none());
+    drop(iter);
+    assert!(p.is_synced());
+}
+
 #[test]
 fn test_correct_but_unsupported_source() {
     let mut p = Parser::new();
-    let sentence = b"$LCVTG,089.0,T,,,15.2,N,,*67\r\n";
+    let sentence = b"$ZZVTG,089.0,T,,,15.2,N,,*68\r\n";
     let mut parsed = false;
     for b in sentence.iter() {
         let r = p.parse_from_byte(*b);
@@ -84,6 +548,48 @@ fn test_correct_but_unsupported_nmea_block() {
     assert!(parsed);
 }
 
+#[test]
+fn test_marine_sources() {
+    let mut p = Parser::new();
+    assert_eq!(
+        p.parse_from_bytes(b"$IIVTG,089.0,T,,,15.2,N,,,A*05\r\n")
+            .next()
+            .unwrap(),
+        Ok(ParseResult::VTG(Some(VTG {
+            source: Source::IntegratedInstrumentation,
+            course: Some(From::from(89.0)),
+            magnetic: None,
+            speed: coords::Speed::from_knots(15.2),
+            mode: Mode::Autonomous
+        })))
+    );
+    // Talkers are recognized, but sentences are not supported yet
+    for sentence in [
+        &b"$SDDPT,12.5,0.5*64\r\n"[..],
+        &b"$WIMWV,214.8,R,0.1,K,A*28\r\n"[..],
+        &b"$HCHDG,98.3,0.0,E,12.6,W*57\r\n"[..],
+        &b"$VWVHW,,,,,5.2,N,9.6,K*45\r\n"[..],
+    ]
+    .iter()
+    {
+        assert_eq!(
+            p.parse_from_bytes(sentence).next().unwrap(),
+            Err("Unsupported sentence type.")
+        );
+    }
+    let mut p = Parser::new().source_only(Source::ECDIS);
+    assert!(p
+        .parse_from_bytes(b"$IIVTG,089.0,T,,,15.2,N,,,A*05\r\n")
+        .next()
+        .is_none());
+    let rmc = b"$ECRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*47\r\n";
+    let result = p.parse_from_bytes(&rmc[..]).next().unwrap();
+    match result {
+        Ok(ParseResult::RMC(Some(rmc))) => assert_eq!(rmc.source, Source::ECDIS),
+        _ => panic!("Unexpected ParseResult variant while parsing RMC data."),
+    }
+}
+
 #[test]
 fn test_stream_slice() {
     let mut p = Parser::new();
@@ -108,6 +614,147 @@ fn test_stream_slice() {
     assert_eq!(parse_count, 2);
 }
 
+#[test]
+fn test_trailing_whitespace() {
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12 \t \r\n";
+    let mut p = Parser::new();
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next(),
+        Some(Err("NMEA format error!"))
+    );
+    let mut p = Parser::new().tolerant_whitespace(true);
+    assert!(matches!(
+        p.parse_from_bytes(&b[..]).next(),
+        Some(Ok(ParseResult::VTG(Some(_))))
+    ));
+    let mut p = Parser::new().tolerant_whitespace(true).accept_lf_only(true);
+    assert!(matches!(
+        p.parse_from_bytes(&b"$GPVTG,089.0,T,,,15.2,N,,,A*12  \n"[..])
+            .next(),
+        Some(Ok(ParseResult::VTG(Some(_))))
+    ));
+}
+
+#[test]
+fn test_vtg_lf_only() {
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\n";
+    let mut p = Parser::new();
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next().unwrap(),
+        Err("NMEA format error!")
+    );
+    let mut p = Parser::new().accept_lf_only(true);
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next().unwrap(),
+        Ok(ParseResult::VTG(Some(VTG {
+            source: Source::GPS,
+            course: Some(From::from(89.0)),
+            magnetic: None,
+            speed: coords::Speed::from_knots(15.2),
+            mode: Mode::Autonomous
+        })))
+    );
+}
+
+#[test]
+fn test_rtk_modes() {
+    let mut p = Parser::new();
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,R*45\r\n\
+$GPVTG,089.0,T,,,15.2,N,,,F*15\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::RMC(Some(rmc)))) => {
+            assert_eq!(rmc.mode, Mode::RtkFixed);
+            assert!(rmc.mode.is_valid());
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing RMC data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::VTG(Some(vtg)))) => {
+            assert_eq!(vtg.mode, Mode::RtkFloat);
+            assert!(vtg.mode.is_valid());
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing VTG data."),
+    }
+}
+
+#[test]
+fn test_vtg_without_mode() {
+    let mut p = Parser::new();
+    let b = b"$GPVTG,089.0,T,,,15.2,N,28.2,K*22\r\n";
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next(),
+        Some(Ok(ParseResult::VTG(Some(VTG {
+            source: Source::GPS,
+            course: Some(From::from(89.0)),
+            magnetic: None,
+            speed: coords::Speed::from_knots(15.2),
+            mode: Mode::Autonomous
+        }))))
+    );
+}
+
+#[test]
+fn test_is_stationary() {
+    let mut p = Parser::new();
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.01,25.82,200906,,,A*51\r\n\
+$GPVTG,089.0,T,,,0.01,N,,,A*15\r\n\
+$GPVTG,089.0,T,,,5.0,N,,,A*21\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::RMC(Some(rmc)))) => {
+            assert!(rmc.is_stationary(coords::Speed::from_knots(0.2)))
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing RMC data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::VTG(Some(vtg)))) => {
+            assert!(vtg.is_stationary(coords::Speed::from_knots(0.2)))
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing VTG data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::VTG(Some(vtg)))) => {
+            assert!(!vtg.is_stationary(coords::Speed::from_knots(0.2)))
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing VTG data."),
+    }
+}
+
+#[test]
+fn test_vtg_magnetic_variation() {
+    let mut p = Parser::new();
+    let b = b"$GPVTG,005.0,T,355.0,M,15.2,N,28.2,K,A*2B\r\n\
+$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::VTG(Some(vtg)))) => {
+            assert_eq!(vtg.magnetic_variation(), Some(10.0));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing VTG data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::VTG(Some(vtg)))) => assert_eq!(vtg.magnetic_variation(), None),
+        _ => panic!("Unexpected ParseResult variant while parsing VTG data."),
+    }
+}
+
+#[test]
+fn test_vtg_kph_speed_only() {
+    let mut p = Parser::new();
+    let b = b"$GPVTG,089.0,T,,,,,15.2,K,A*17\r\n";
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next(),
+        Some(Ok(ParseResult::VTG(Some(VTG {
+            source: Source::GPS,
+            course: Some(From::from(89.0)),
+            magnetic: None,
+            speed: coords::Speed::from_kph(15.2),
+            mode: Mode::Autonomous
+        }))))
+    );
+}
+
 #[test]
 fn test_correct_vtg() {
     let mut p = Parser::new();
@@ -133,6 +780,30 @@ fn test_correct_vtg() {
     assert!(parsed);
 }
 
+#[test]
+fn test_lowercase_checksum() {
+    let mut p = Parser::new();
+    let upper = p
+        .parse_from_bytes(b"$GPVTG,089.0,T,,,15.2,N,,,M*1E\r\n")
+        .next()
+        .unwrap();
+    let lower = p
+        .parse_from_bytes(b"$GPVTG,089.0,T,,,15.2,N,,,M*1e\r\n")
+        .next()
+        .unwrap();
+    assert_eq!(
+        lower,
+        Ok(ParseResult::VTG(Some(VTG {
+            source: Source::GPS,
+            course: Some(From::from(89.0)),
+            magnetic: None,
+            speed: coords::Speed::from_knots(15.2),
+            mode: Mode::Manual
+        })))
+    );
+    assert_eq!(lower, upper);
+}
+
 #[test]
 fn test_correct_rmc() {
     let mut p = Parser::new();
@@ -162,7 +833,10 @@ fn test_correct_rmc() {
                     speed: coords::Speed::from_knots(0.06),
                     course: Some(From::from(25.82)),
                     magnetic: None,
-                    mode: Mode::Autonomous
+                    magnetic_variation: None,
+                    magnetic_variation_dir: None,
+                    mode: Mode::Autonomous,
+                    nav_status: None
                 })))
             );
             parsed = true;
@@ -172,6 +846,31 @@ fn test_correct_rmc() {
     assert!(parsed);
 }
 
+#[test]
+fn test_rmc_nav_status() {
+    let mut p = Parser::new();
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n\
+$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A,V*2C\r\n\
+$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A,S*29\r\n";
+    let mut statuses = [None, None, None];
+    let mut count = 0;
+    for result in p.parse_from_bytes(&b[..]) {
+        match result {
+            Ok(ParseResult::RMC(Some(rmc))) => {
+                assert_eq!(rmc.mode, Mode::Autonomous);
+                statuses[count] = rmc.nav_status;
+                count += 1;
+            }
+            _ => panic!("Unexpected ParseResult variant while parsing RMC data."),
+        }
+    }
+    assert_eq!(count, 3);
+    assert_eq!(
+        statuses,
+        [None, Some(NavStatus::NotValid), Some(NavStatus::Safe)]
+    );
+}
+
 #[test]
 fn test_correct_gga() {
     let mut p = Parser::new();
@@ -207,6 +906,154 @@ fn test_correct_gga() {
     assert!(parsed);
 }
 
+#[test]
+fn test_gga_altitudes() {
+    let mut p = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n\
+$GPGGA,160545,5008.6263,N,01422.4224,E,1,03,3.6,,M,45.0,M,,*61\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.altitude_msl(), Some(9.0));
+            assert_eq!(
+                gga.dop(),
+                quality::Dop {
+                    pdop: None,
+                    hdop: 0.6,
+                    vdop: None,
+                }
+            );
+            assert_eq!(gga.altitude_ellipsoidal(), Some(27.0));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.altitude_msl(), None);
+            assert_eq!(gga.altitude_ellipsoidal(), None);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+}
+
+#[test]
+fn test_gga_age_dgps() {
+    let mut p = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,1.5,0120*4B\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,-1.5,0120*66\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.age_dgps, Some(Duration::from_millis(1500)));
+            assert_eq!(gga.dgps_station_id, Some(120));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+    assert_eq!(
+        iter.next(),
+        Some(Err("Duration should be finite and not negative"))
+    );
+}
+
+#[test]
+fn test_gga_zero_and_empty_age_dgps() {
+    let mut p = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,0,0031*50\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.age_dgps, Some(Duration::from_secs(0)));
+            assert_eq!(gga.dgps_station_id, Some(31));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.age_dgps, None);
+            assert_eq!(gga.dgps_station_id, None);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+}
+
+#[test]
+fn test_gga_dgps_station_id_with_leading_zeros() {
+    let mut p = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,1.5,0005*4D\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,1.5,0000*48\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n";
+    let station_ids: Vec<_> = p
+        .parse_from_bytes(&b[..])
+        .map(|result| match result {
+            Ok(ParseResult::GGA(Some(gga))) => gga.dgps_station_id,
+            _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+        })
+        .collect();
+    assert_eq!(station_ids, [Some(5), Some(0), None]);
+}
+
+#[test]
+fn test_gga_vendor_quality() {
+    let mut p = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,9,07,0.6,9.0,M,18.0,M,,*69\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,10,07,0.6,9.0,M,18.0,M,,*51\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.gps_quality, GPSQuality::Other(9));
+            assert_eq!(gga.gps_quality.as_u8(), 9);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    };
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.gps_quality, GPSQuality::Other(10));
+            assert_eq!(gga.gps_quality.as_u8(), 10);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    };
+}
+
+#[test]
+fn test_gga_approximate_fix_type() {
+    let mut p = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,1,03,2.5,,M,,M,,*54\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,0,00,99.9,,,,,,*68\r\n";
+    let mut fix_types = p.parse_from_bytes(&b[..]).map(|result| match result {
+        Ok(ParseResult::GGA(Some(gga))) => gga.approximate_fix_type(),
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    });
+    assert_eq!(fix_types.next(), Some(FixType::Fix3D));
+    assert_eq!(fix_types.next(), Some(FixType::Fix2D));
+    assert_eq!(fix_types.next(), Some(FixType::NoFix));
+    assert_eq!(fix_types.next(), None);
+}
+
+#[test]
+fn test_gga_signed_altitudes() {
+    let mut p = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,-50.0,M,-30.0,M,,*54\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,+9.0,M,18.0,M,,*49\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.altitude_msl(), Some(-50.0));
+            assert_eq!(gga.geoidal_separation, Some(-30.0));
+            assert_eq!(gga.altitude_ellipsoidal(), Some(-80.0));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => {
+            assert_eq!(gga.altitude_msl(), Some(9.0));
+            assert_eq!(gga.altitude_ellipsoidal(), Some(27.0));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    }
+}
+
 #[test]
 fn test_correct_gga_without_altitude() {
     let mut p = Parser::new();
@@ -281,7 +1128,10 @@ fn test_correct_rmc2() {
                     speed: coords::Speed::from_knots(0.01),
                     course: Some(From::from(255.6)),
                     magnetic: Some(From::from(246.90001)),
-                    mode: Mode::Autonomous
+                    magnetic_variation: Some(8.7),
+                    magnetic_variation_dir: Some(Hemisphere::East),
+                    mode: Mode::Autonomous,
+                    nav_status: None
                 })))
             );
             parsed = true;
@@ -314,6 +1164,28 @@ fn test_correct_gll() {
     }
 }
 
+#[test]
+fn test_gll_without_checksum() {
+    let b = b"$GPGLL,4916.45,N,12311.12,W,225444,A\r\n";
+    let mut p = Parser::new();
+    assert!(p.parse_from_bytes(&b[..]).next().is_none());
+    let mut p = Parser::new().allow_missing_checksum(true);
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next().unwrap(),
+        Ok(ParseResult::GLL(Some(GLL {
+            source: Source::GPS,
+            time: datetime::Time {
+                hours: 22,
+                minutes: 54,
+                seconds: 44.0
+            },
+            latitude: TryFrom::try_from(49.2741666667).unwrap(),
+            longitude: TryFrom::try_from(-123.18533333334).unwrap(),
+            mode: Mode::Autonomous
+        })))
+    );
+}
+
 #[test]
 fn test_correct_gsv() {
     let mut p = Parser::new();
@@ -392,6 +1264,31 @@ fn test_correct_gsv2() {
     }
 }
 #[test]
+fn test_qzss_beidou_navic_gsv() {
+    let mut p = Parser::new();
+    let b = b"$QZGSV,1,1,01,193,62,173,40*6A\r\n$GBGSV,1,1,01,201,45,120,38*60\r\n$GIGSV,1,1,01,02,40,100,35*50\r\n";
+    let mut sources = [Source::GPS; 3];
+    let mut count = 0;
+    for result in p.parse_from_bytes(&b[..]) {
+        match result {
+            Ok(ParseResult::GSV(Some(gsv))) => {
+                sources[count] = gsv.source;
+                count += 1;
+            }
+            _ => panic!("Unexpected ParseResult variant while parsing GSV data."),
+        }
+    }
+    assert_eq!(count, 3);
+    assert_eq!(sources, [Source::QZSS, Source::Beidou, Source::NavIC]);
+
+    let mut p = Parser::new().source_only(Source::QZSS);
+    let mut results = p.parse_from_bytes(&b[..]);
+    assert!(results.next().is_some());
+    assert!(results.next().is_none());
+}
+
+#[test]
+#[cfg(feature = "mtk")]
 fn test_correct_pmtk() {
     let mut p = Parser::new();
     let b = b"$PMTKSPF,2*59\r\n";
@@ -407,6 +1304,156 @@ fn test_correct_pmtk() {
     }
 }
 
+#[test]
+#[cfg(feature = "garmin")]
+fn test_correct_pgrme() {
+    let mut p = Parser::new();
+    let b = b"$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n";
+    {
+        let mut iter = p.parse_from_bytes(&b[..]);
+        assert_eq!(
+            iter.next().unwrap(),
+            Ok(ParseResult::PGRME(Some(PGRME {
+                source: Source::Garmin,
+                horizontal_error: 15.0,
+                vertical_error: 45.0,
+                spherical_error: 25.0
+            })))
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "garmin")]
+fn test_pgrme_accuracy() {
+    let mut p = Parser::new();
+    let b = b"$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n";
+    match p.parse_from_bytes(&b[..]).next() {
+        Some(Ok(ParseResult::PGRME(Some(pgrme)))) => assert_eq!(
+            pgrme.accuracy(),
+            quality::Accuracy {
+                horizontal: Some(15.0),
+                vertical: Some(45.0),
+                position: Some(25.0)
+            }
+        ),
+        _ => panic!("Unexpected ParseResult variant while parsing PGRME data."),
+    };
+}
+
+#[test]
+#[cfg(feature = "garmin")]
+fn test_correct_pgrmz() {
+    let mut p = Parser::new();
+    let b = b"$PGRMZ,2282,f,3*21\r\n";
+    {
+        let mut iter = p.parse_from_bytes(&b[..]);
+        let pgrmz = match iter.next().unwrap().unwrap() {
+            ParseResult::PGRMZ(Some(pgrmz)) => pgrmz,
+            _ => {
+                panic!("Unexpected ParseResult variant while parsing PGRMZ data.");
+            }
+        };
+        assert_eq!(
+            pgrmz,
+            PGRMZ {
+                source: Source::Garmin,
+                altitude_feet: 2282.0,
+                position_fix_dimension: FixType::Fix3D
+            }
+        );
+        assert!((pgrmz.as_meters() - 695.5536).abs() < 0.001);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "ublox", not(feature = "strict")))]
+fn test_correct_pubx00() {
+    let mut p = Parser::new();
+    let b = b"$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F\r\n";
+    {
+        let mut iter = p.parse_from_bytes(&b[..]);
+        let pubx = match iter.next().unwrap().unwrap() {
+            ParseResult::PUBX00(Some(pubx)) => pubx,
+            _ => {
+                panic!("Unexpected ParseResult variant while parsing PUBX,00 data.");
+            }
+        };
+        assert_eq!(pubx.source, Source::UBlox);
+        assert_eq!(
+            pubx.time,
+            datetime::Time {
+                hours: 8,
+                minutes: 13,
+                seconds: 50.0
+            }
+        );
+        assert_eq!(pubx.latitude, TryFrom::try_from(47.2852201667).unwrap());
+        assert_eq!(pubx.longitude, TryFrom::try_from(8.5652531167).unwrap());
+        assert_eq!(pubx.altitude_ref, 546.589);
+        assert_eq!(pubx.nav_status, PUBXNavStatus::StandAlone3D);
+        assert_eq!(pubx.horizontal_accuracy, 2.1);
+        assert_eq!(pubx.vertical_accuracy, 2.0);
+        assert_eq!(
+            pubx.accuracy(),
+            quality::Accuracy {
+                horizontal: Some(2.1),
+                vertical: Some(2.0),
+                position: None
+            }
+        );
+        assert_eq!(pubx.speed, coords::Speed::from_kph(0.007));
+        assert_eq!(pubx.course, Some(From::from(77.52)));
+        assert_eq!(pubx.vertical_velocity, 0.007);
+        assert_eq!(pubx.age_dgps, None);
+        assert_eq!(pubx.hdop, 0.92);
+        assert_eq!(pubx.vdop, 1.19);
+        assert_eq!(pubx.tdop, 0.77);
+        assert_eq!(pubx.gps_sat_in_use, 9);
+        assert_eq!(pubx.glonass_sat_in_use, 0);
+        assert!(!pubx.dead_reckoning);
+    }
+}
+
+#[test]
+#[cfg(feature = "ublox")]
+fn test_correct_pubx04() {
+    let mut p = Parser::new();
+    let b = b"$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D\r\n";
+    {
+        let mut iter = p.parse_from_bytes(&b[..]);
+        let pubx = match iter.next().unwrap().unwrap() {
+            ParseResult::PUBX04(Some(pubx)) => pubx,
+            _ => {
+                panic!("Unexpected ParseResult variant while parsing PUBX,04 data.");
+            }
+        };
+        assert_eq!(pubx.source, Source::UBlox);
+        assert_eq!(
+            pubx.time,
+            datetime::Time {
+                hours: 7,
+                minutes: 37,
+                seconds: 31.0
+            }
+        );
+        assert_eq!(
+            pubx.date,
+            datetime::Date {
+                day: 9,
+                month: 12,
+                year: 2002
+            }
+        );
+        assert_eq!(pubx.utc_tow, 113851.0);
+        assert_eq!(pubx.week, 1196);
+        assert_eq!(pubx.leap_seconds, 15);
+        assert!(pubx.leap_seconds_default);
+        assert_eq!(pubx.clock_bias_ns, 1930035.0);
+        assert_eq!(pubx.clock_drift_ns_s, -2660.664);
+    }
+}
+
 #[test]
 fn test_correct_gsa() {
     let mut p = Parser::new();
@@ -426,9 +1473,556 @@ fn test_correct_gsa() {
         assert_eq!(gsa.pdop, 1.2);
         assert_eq!(gsa.hdop, 0.7);
         assert_eq!(gsa.vdop, 1.0);
+        assert_eq!(gsa.system_id, None);
+        assert_eq!(
+            gsa.dop(),
+            quality::Dop {
+                pdop: Some(1.2),
+                hdop: 0.7,
+                vdop: Some(1.0),
+            }
+        );
+    }
+}
+
+#[test]
+fn test_gsa_correlate_satellites() {
+    let mut p = Parser::new();
+    let b = b"$GNGSA,A,3,21,5,29,25,12,10,26,2,,,,,1.2,0.7,1.0*27\r\n";
+    let gsa = match p.parse_from_bytes(&b[..]).next() {
+        Some(Ok(ParseResult::GSA(Some(gsa)))) => gsa,
+        _ => panic!("Unexpected ParseResult variant while parsing GSA data."),
+    };
+    let sats = [
+        satellite::Satellite {
+            prn: 5,
+            elevation: 40,
+            azimuth: 83,
+            snr: Some(46),
+        },
+        satellite::Satellite {
+            prn: 7,
+            elevation: 15,
+            azimuth: 270,
+            snr: None,
+        },
+        satellite::Satellite {
+            prn: 21,
+            elevation: 62,
+            azimuth: 110,
+            snr: Some(40),
+        },
+    ];
+    let mut used = gsa.correlate(&sats);
+    assert_eq!(used.next(), Some(&sats[0]));
+    assert_eq!(used.next(), Some(&sats[2]));
+    assert_eq!(used.next(), None);
+}
+
+#[test]
+fn test_gsa_too_many_prns() {
+    let mut p = Parser::new();
+    let b = b"$GNGSA,A,3,21,5,29,25,12,10,26,2,7,8,9,11,31,1.2,0.7,1.0*3F\r\n\
+$GNGSA,A,3,21,5,29,25,12,10,26,2,7,8,9,11,31,1.2,0.7,1.0,1*22\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert_eq!(iter.next(), Some(Err("Malformed GSA field count!")));
+    assert_eq!(iter.next(), Some(Err("Malformed GSA field count!")));
+}
+
+#[test]
+fn test_correct_msk() {
+    let mut p = Parser::new();
+    let b = b"$CRMSK,318.0,A,100,M,2*43\r\n";
+    assert_eq!(
+        p.parse_from_bytes(&b[..]).next(),
+        Some(Ok(ParseResult::MSK(Some(MSK {
+            source: Source::BeaconReceiver,
+            frequency_khz: 318.0,
+            frequency_mode: 'A',
+            bit_rate: 100,
+            bit_rate_mode: 'M',
+            mss: Some(2),
+        }))))
+    );
+}
+
+#[test]
+fn test_correct_bwc() {
+    let mut p = Parser::new();
+    let b = b"$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM,A*4C\r\n\
+$GPBWC,220516,,,,,,T,,M,,N,EGLM,N*75\r\n\
+$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21\r\n\
+$GPBWC,220516,,,,,,T,,M,,N,*14\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::BWC(Some(bwc)))) => {
+            assert_eq!(bwc.source, Source::GPS);
+            assert_eq!(
+                bwc.time,
+                datetime::Time {
+                    hours: 22,
+                    minutes: 5,
+                    seconds: 16.0
+                }
+            );
+            assert!((bwc.latitude.as_f64() - 51.500333).abs() < 0.00001);
+            assert!((bwc.longitude.as_f64() + 0.772333).abs() < 0.00001);
+            assert_eq!(bwc.bearing_true, coords::Course { degrees: 213.8 });
+            assert!(bwc.bearing_magnetic.is_some());
+            assert_eq!(bwc.distance_nm, 4.6);
+            assert_eq!(bwc.waypoint_id.unwrap().as_str(), "EGLM");
+            assert_eq!(bwc.mode, Mode::Autonomous);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing BWC data."),
+    }
+    assert_eq!(iter.next(), Some(Ok(ParseResult::BWC(None))));
+    match iter.next() {
+        Some(Ok(ParseResult::BWC(Some(bwc)))) => assert_eq!(bwc.mode, Mode::Autonomous),
+        _ => panic!("Unexpected ParseResult variant while parsing BWC data."),
+    }
+    assert_eq!(iter.next(), Some(Ok(ParseResult::BWC(None))));
+}
+
+#[test]
+fn test_correct_aam() {
+    let mut p = Parser::new();
+    let b = b"$GPAAM,A,A,0.10,N,WPTNME*32\r\n";
+    let result = p.parse_from_bytes(&b[..]).next();
+    match result {
+        Some(Ok(ParseResult::AAM(Some(aam)))) => {
+            assert_eq!(aam.source, Source::GPS);
+            assert!(aam.arrival_circle_entered);
+            assert!(aam.perpendicular_passed);
+            assert_eq!(aam.circle_radius_nm, 0.1);
+            assert_eq!(aam.waypoint_id.unwrap().as_str(), "WPTNME");
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing AAM data."),
+    }
+}
+
+#[test]
+fn test_correct_ths() {
+    let mut p = Parser::new();
+    let b = b"$GPTHS,338.01,A*0E\r\n$GPTHS,,V*0E\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert_eq!(
+        iter.next(),
+        Some(Ok(ParseResult::THS(Some(THS {
+            source: Source::GPS,
+            heading: coords::Course { degrees: 338.01 },
+            status: Mode::Autonomous,
+        }))))
+    );
+    assert_eq!(iter.next(), Some(Ok(ParseResult::THS(None))));
+}
+
+#[test]
+fn test_correct_vbw() {
+    let mut p = Parser::new();
+    let b = b"$VDVBW,12.3,-0.2,A,11.8,-0.1,A*5A\r\n$VDVBW,,,V,,,V*51\r\n$VDVBW,,,,,,*51\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert_eq!(
+        iter.next(),
+        Some(Ok(ParseResult::VBW(Some(VBW {
+            source: Source::DopplerVelocity,
+            longitudinal_water: 12.3,
+            transverse_water: -0.2,
+            water_valid: true,
+            longitudinal_ground: 11.8,
+            transverse_ground: -0.1,
+            ground_valid: true,
+        }))))
+    );
+    assert_eq!(iter.next(), Some(Ok(ParseResult::VBW(None))));
+    assert_eq!(iter.next(), Some(Ok(ParseResult::VBW(None))));
+}
+
+#[test]
+fn test_correct_mwd() {
+    let mut p = Parser::new();
+    let b = b"$WIMWD,015.0,T,013.0,M,10.1,N,5.2,M*6B\r\n\
+$WIMWD,,T,,M,,N,5.2,M*73\r\n\
+$WIMWD,,T,,M,,N,,M*5A\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::MWD(Some(mwd)))) => {
+            assert_eq!(mwd.source, Source::WeatherInstruments);
+            assert_eq!(mwd.direction_true, Some(coords::Course { degrees: 15.0 }));
+            assert!(mwd.direction_magnetic.is_some());
+            assert_eq!(mwd.speed, coords::Speed::from_knots(10.1));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing MWD data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::MWD(Some(mwd)))) => {
+            assert_eq!(mwd.direction_true, None);
+            assert!((mwd.speed.as_mps() - 5.2).abs() < 0.001);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing MWD data."),
+    }
+    assert_eq!(iter.next(), Some(Ok(ParseResult::MWD(None))));
+}
+
+#[test]
+fn test_correct_vdr() {
+    let mut p = Parser::new();
+    let b = b"$IIVDR,201.1,T,199.9,M,3.5,N*35\r\n$IIVDR,,T,,M,,N*17\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::VDR(Some(vdr)))) => {
+            assert_eq!(vdr.source, Source::IntegratedInstrumentation);
+            assert_eq!(vdr.direction_true, coords::Course { degrees: 201.1 });
+            assert!(vdr.direction_magnetic.is_some());
+            assert_eq!(vdr.speed, coords::Speed::from_knots(3.5));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing VDR data."),
+    }
+    assert_eq!(iter.next(), Some(Ok(ParseResult::VDR(None))));
+}
+
+#[test]
+fn test_correct_osd() {
+    let mut p = Parser::new();
+    let b = b"$IIOSD,35.1,A,36.0,P,10.2,P,15.3,0.1,N*52\r\n$IIOSD,35.1,A,,P,18.9,W,,0.0,K*50\r\n$IIOSD,,V,,,,,,,N*6C\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::OSD(Some(osd)))) => {
+            assert_eq!(osd.source, Source::IntegratedInstrumentation);
+            assert_eq!(osd.heading, coords::Course { degrees: 35.1 });
+            assert_eq!(osd.heading_status, 'A');
+            assert_eq!(osd.course_true, Some(coords::Course { degrees: 36.0 }));
+            assert_eq!(osd.course_reference, 'P');
+            assert_eq!(osd.speed, coords::Speed::from_knots(10.2));
+            assert_eq!(osd.speed_reference, 'P');
+            assert_eq!(osd.set, Some(coords::Course { degrees: 15.3 }));
+            assert_eq!(osd.drift, coords::Speed::from_knots(0.1));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing OSD data."),
+    }
+    match iter.next() {
+        Some(Ok(ParseResult::OSD(Some(osd)))) => {
+            assert_eq!(osd.course_true, None);
+            assert_eq!(osd.speed, coords::Speed::from_kph(18.9));
+            assert_eq!(osd.speed_reference, 'W');
+            assert_eq!(osd.set, None);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing OSD data."),
+    }
+    assert_eq!(iter.next(), Some(Ok(ParseResult::OSD(None))));
+}
+
+#[test]
+fn test_correct_rma() {
+    let mut p = Parser::new();
+    let b = b"$LCRMA,A,5542.2389,N,03741.6063,E,12345.6,67890.1,10.5,25.8,11.2,E,A*3A\r\n$LCRMA,V,,,,,,,,,,,N*49\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::RMA(Some(rma)))) => {
+            assert_eq!(rma.source, Source::LoranC);
+            assert_eq!(rma.latitude, TryFrom::try_from(55.703981666666664).unwrap());
+            assert_eq!(rma.longitude, TryFrom::try_from(37.69343833333333).unwrap());
+            assert_eq!(rma.time_difference_a, Some(12345.6));
+            assert_eq!(rma.time_difference_b, Some(67890.1));
+            assert_eq!(rma.speed, coords::Speed::from_knots(10.5));
+            assert_eq!(rma.course, coords::Course { degrees: 25.8 });
+            assert_eq!(rma.magnetic_variation, Some(11.2));
+            assert_eq!(rma.magnetic_variation_dir, Some(Hemisphere::East));
+            assert_eq!(rma.mode, Mode::Autonomous);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing RMA data."),
+    }
+    assert_eq!(iter.next(), Some(Ok(ParseResult::RMA(None))));
+}
+
+#[test]
+fn test_correct_rte() {
+    let mut p = Parser::new();
+    let b = b"$GPRTE,2,1,c,0,W3IWI,DRIVWY,32,75608,25*26\r\n\
+$GPRTE,1,1,w,R1,A,B,C,D,E,F,G,H,I,J,K*2C\r\n\
+$GPRTE,,,,,*78\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    match iter.next() {
+        Some(Ok(ParseResult::RTE(Some(rte)))) => {
+            assert_eq!(rte.source, Source::GPS);
+            assert_eq!(rte.total_messages, 2);
+            assert_eq!(rte.message_number, 1);
+            assert_eq!(rte.route_type, 'c');
+            assert_eq!(rte.route_id.as_ref().map(|id| id.as_str()), Some("0"));
+            let mut waypoints = rte.waypoints();
+            assert_eq!(waypoints.next(), Some("W3IWI"));
+            assert_eq!(waypoints.next(), Some("DRIVWY"));
+            assert_eq!(waypoints.next(), Some("32"));
+            assert_eq!(waypoints.next(), Some("75608"));
+            assert_eq!(waypoints.next(), Some("25"));
+            assert_eq!(waypoints.next(), None);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing RTE data."),
+    }
+    assert_eq!(
+        iter.next(),
+        Some(Err("Too many waypoints in RTE sentence!"))
+    );
+    assert_eq!(iter.next(), Some(Ok(ParseResult::RTE(None))));
+}
+
+#[test]
+fn test_parse_result_from_fields() {
+    let mut fields = "125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A".split(',');
+    match ParseResult::from_fields(Source::GPS, Sentence::RMC, &mut fields) {
+        Ok(ParseResult::RMC(Some(rmc))) => {
+            assert_eq!(rmc.source, Source::GPS);
+            assert_eq!(rmc.speed, coords::Speed::from_knots(0.06));
+            assert_eq!(rmc.mode, Mode::Autonomous);
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing RMC data."),
+    }
+    let mut fields = ",,,,,,,,,,,,,".split(',');
+    assert_eq!(
+        ParseResult::from_fields(Source::GPS, Sentence::GGA, &mut fields),
+        Ok(ParseResult::GGA(None))
+    );
+}
+
+#[test]
+fn test_raw_sentences() {
+    let mut p = Parser::new();
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
+    let mut iter = p.raw_sentences(&b[..]);
+    assert_eq!(
+        iter.next().unwrap().unwrap().as_bytes(),
+        &b"GPVTG,089.0,T,,,15.2,N,,,A"[..]
+    );
+    assert_eq!(
+        iter.next().unwrap().unwrap().as_bytes(),
+        &b"GPGLL,4916.45,N,12311.12,W,225444,A"[..]
+    );
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_parse_payload() {
+    let p = Parser::new();
+    let payload = "GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A";
+    match p.parse_payload(payload) {
+        Ok(Some(ParseResult::RMC(Some(rmc)))) => {
+            assert_eq!(rmc.source, Source::GPS);
+            assert_eq!(rmc.datetime.time.seconds, 4.049);
+            assert_eq!(rmc.speed, coords::Speed::from_knots(0.06));
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing RMC payload."),
+    }
+    assert_eq!(
+        p.parse_payload("GPGGA,,,,,,,,,,,,,,"),
+        Ok(Some(ParseResult::GGA(None)))
+    );
+    assert!(p.parse_payload("GPXYZ,1,2").is_err());
+    let p = Parser::new().sentence_only(Sentence::GGA).skip_empty(true);
+    assert_eq!(p.parse_payload(payload), Ok(None));
+    assert_eq!(p.parse_payload("GPGGA,,,,,,,,,,,,,,"), Ok(None));
+}
+
+#[test]
+fn test_parse_field_only() {
+    let mut p = Parser::new();
+    assert_eq!(p.parse_field_only(Sentence::GGA, 6), None);
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n";
+    assert!(p.raw_sentences(&b[..]).next().unwrap().is_ok());
+    assert_eq!(p.parse_field_only(Sentence::GGA, 6), Some("2"));
+    assert_eq!(p.parse_field_only(Sentence::GGA, 0), Some("GPGGA"));
+    assert_eq!(p.parse_field_only(Sentence::GGA, 14), Some(""));
+    assert_eq!(p.parse_field_only(Sentence::GGA, 15), None);
+    assert_eq!(p.parse_field_only(Sentence::RMC, 6), None);
+
+    // Field is not available while next sentence is incomplete
+    assert!(p.raw_sentences(&b"$GPGGA,1456"[..]).next().is_none());
+    assert_eq!(p.parse_field_only(Sentence::GGA, 6), None);
+}
+
+#[test]
+fn test_feed() {
+    let mut p = Parser::new();
+    let mut out = [None, None, None, None];
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n$GPGLL";
+    assert_eq!(p.feed(&b[..], &mut out), 2);
+    assert!(matches!(out[0], Some(Ok(ParseResult::VTG(Some(_))))));
+    assert!(matches!(out[1], Some(Ok(ParseResult::GLL(Some(_))))));
+    assert_eq!(out[2], None);
+    assert_eq!(out[3], None);
+
+    let mut small = [None];
+    assert_eq!(
+        p.feed(&b",4916.45,N,12311.12,W,225444,A*31\r\n"[..], &mut small),
+        1
+    );
+    assert!(matches!(small[0], Some(Ok(ParseResult::GLL(Some(_))))));
+    assert_eq!(p.feed(&b[..], &mut small), 2);
+    assert!(matches!(small[0], Some(Ok(ParseResult::VTG(Some(_))))));
+}
+
+#[test]
+fn test_gsv_too_many_satellites() {
+    let mut p = Parser::new();
+    let b =
+        b"$GPGSV,2,1,08,21,44,141,47,15,14,049,44,06,31,255,46,03,25,280,44,10,10,100,30*42\r\n\
+$GPGSV,2,1,08,21,44,141,47,15,14,049,44,06,31,255,46,03,25,280,44,10,,,*71\r\n\
+$GPGSV,2,1,08,21,44,141,47,15,14,049,44,06,31,255,46,03,25,280,44,1*6D\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    assert_eq!(
+        iter.next(),
+        Some(Err("Too many satellites in GSV message!"))
+    );
+    assert_eq!(
+        iter.next(),
+        Some(Err("Too many satellites in GSV message!"))
+    );
+    match iter.next() {
+        Some(Ok(ParseResult::GSV(Some(gsv)))) => {
+            assert_eq!(gsv.get_in_view_satellites().len(), 4)
+        }
+        _ => panic!("Unexpected ParseResult variant while parsing GSV data."),
     }
 }
 
+#[test]
+fn test_gsv_collector() {
+    let mut p = Parser::new();
+    let mut collector = GsvCollector::new();
+    let b = b"$GPGSV,2,1,06,21,44,141,47,15,14,049,44,06,31,255,,03,25,280,40*78\r\n\
+$GPGSV,2,2,06,10,10,100,,12,20,200,45*7C\r\n\
+$GLGSV,1,1,02,68,37,284,50,69,10,030,*6B\r\n";
+    for result in p.parse_from_bytes(&b[..]) {
+        match result {
+            Ok(ParseResult::GSV(Some(gsv))) => collector.update(&gsv),
+            _ => panic!("Unexpected ParseResult variant while parsing GSV data."),
+        }
+    }
+    assert_eq!(collector.satellites().len(), 8);
+    assert_eq!(collector.average_snr(), Some(45.2));
+    assert_eq!(collector.max_snr(), Some(50));
+
+    let b = b"$GPGSV,1,1,01,21,44,141,30*4C\r\n";
+    for result in p.parse_from_bytes(&b[..]) {
+        match result {
+            Ok(ParseResult::GSV(Some(gsv))) => collector.update(&gsv),
+            _ => panic!("Unexpected ParseResult variant while parsing GSV data."),
+        }
+    }
+    assert_eq!(collector.satellites().len(), 1);
+    assert_eq!(collector.average_snr(), Some(30.0));
+    assert_eq!(collector.max_snr(), Some(30));
+
+    collector.clear();
+    assert_eq!(collector.average_snr(), None);
+    assert_eq!(collector.max_snr(), None);
+}
+
+#[test]
+fn test_gsv_collector_overflow() {
+    let mut p = Parser::new();
+    let mut collector = GsvCollector::<5>::default();
+    let b = b"$GPGSV,2,1,06,21,44,141,47,15,14,049,44,06,31,255,,03,25,280,40*78\r\n\
+$GPGSV,2,2,06,10,10,100,,12,20,200,45*7C\r\n";
+    for result in p.parse_from_bytes(&b[..]) {
+        match result {
+            Ok(ParseResult::GSV(Some(gsv))) => collector.update(&gsv),
+            _ => panic!("Unexpected ParseResult variant while parsing GSV data."),
+        }
+    }
+    assert_eq!(collector.satellites().len(), 5);
+    assert_eq!(collector.satellites()[4].prn, 10);
+    assert!(collector.overflowed());
+
+    collector.clear();
+    assert!(!collector.overflowed());
+    assert!(!GsvCollector::new().overflowed());
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_gsv_collector_to_heapless() {
+    let mut p = Parser::new();
+    let mut collector = GsvCollector::new();
+    let b = b"$GPGSV,2,1,06,21,44,141,47,15,14,049,44,06,31,255,,03,25,280,40*78\r\n\
+$GPGSV,2,2,06,10,10,100,,12,20,200,45*7C\r\n";
+    for result in p.parse_from_bytes(&b[..]) {
+        match result {
+            Ok(ParseResult::GSV(Some(gsv))) => collector.update(&gsv),
+            _ => panic!("Unexpected ParseResult variant while parsing GSV data."),
+        }
+    }
+    let satellites: heapless::Vec<_, 8> = collector.to_heapless();
+    assert_eq!(&satellites[..], collector.satellites());
+    let truncated: heapless::Vec<_, 4> = collector.to_heapless();
+    assert_eq!(&truncated[..], &collector.satellites()[..4]);
+}
+
+#[test]
+fn test_drain_bytes() {
+    let mut p = Parser::new();
+    let mut results = [None, None, None];
+    let mut count = 0;
+    let b = b"$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n$GPGLL";
+    p.drain_bytes(&b[..], |result| {
+        results[count] = Some(result);
+        count += 1;
+    });
+    p.drain_bytes(&b",4916.45,N,12311.12,W,225444,A*31\r\n"[..], |result| {
+        results[count] = Some(result);
+        count += 1;
+    });
+    assert_eq!(count, 3);
+    assert!(matches!(results[0], Some(Ok(ParseResult::VTG(Some(_))))));
+    assert!(matches!(results[1], Some(Ok(ParseResult::GLL(Some(_))))));
+    assert_eq!(results[1], results[2]);
+}
+
+#[test]
+fn test_gsa_collector() {
+    let mut p = Parser::new();
+    let mut collector = GsaCollector::new();
+    let b = b"$GNGSA,A,3,21,5,29,25,12,10,26,2,,,,,1.2,0.7,1.0,1*3A\r\n\
+$GNGSA,A,3,65,67,80,81,,,,,,,,,1.2,0.7,1.0,2*34\r\n\
+$GNGSA,A,3,7,11,,,,,,,,,,,1.2,0.7,1.0,3*01\r\n";
+    for result in p.parse_from_bytes(&b[..]) {
+        match result {
+            Ok(ParseResult::GSA(Some(gsa))) => collector.update(&gsa),
+            _ => panic!("Unexpected ParseResult variant while parsing GSA data."),
+        }
+    }
+    {
+        let mut system_ids = collector.sentences().map(|gsa| gsa.system_id);
+        assert_eq!(system_ids.next(), Some(Some(1)));
+        assert_eq!(system_ids.next(), Some(Some(2)));
+        assert_eq!(system_ids.next(), Some(Some(3)));
+        assert_eq!(system_ids.next(), None);
+    }
+    let mut prns = [0u16; 16];
+    let mut count = 0;
+    for prn in collector.fix_satellites_prn() {
+        prns[count] = prn;
+        count += 1;
+    }
+    assert_eq!(
+        prns[..count],
+        [21, 5, 29, 25, 12, 10, 26, 2, 65, 67, 80, 81, 7, 11]
+    );
+    assert_eq!(collector.pdop(), Some(1.2));
+    assert_eq!(collector.hdop(), Some(0.7));
+    assert_eq!(collector.vdop(), Some(1.0));
+
+    // Next fix starts a new group
+    let b = b"$GNGSA,A,3,21,5,29,25,12,10,,,,,,,1.3,0.8,1.1,1*03\r\n";
+    for result in p.parse_from_bytes(&b[..]) {
+        match result {
+            Ok(ParseResult::GSA(Some(gsa))) => collector.update(&gsa),
+            _ => panic!("Unexpected ParseResult variant while parsing GSA data."),
+        }
+    }
+    assert_eq!(collector.sentences().count(), 1);
+    assert_eq!(collector.fix_satellites_prn().count(), 6);
+    assert_eq!(collector.pdop(), Some(1.3));
+}
+
 #[test]
 fn test_parser_iterator() {
     let mut p = Parser::new();
@@ -456,7 +2050,10 @@ fn test_parser_iterator() {
                 speed: coords::Speed::from_knots(0.06),
                 course: Some(From::from(25.82)),
                 magnetic: None,
-                mode: Mode::Autonomous
+                magnetic_variation: None,
+                magnetic_variation_dir: None,
+                mode: Mode::Autonomous,
+                nav_status: None
             })))
         );
     }
@@ -494,9 +2091,243 @@ fn test_parser_iterator() {
                 speed: coords::Speed::from_knots(0.06),
                 course: Some(From::from(25.82)),
                 magnetic: None,
-                mode: Mode::Autonomous
+                magnetic_variation: None,
+                magnetic_variation_dir: None,
+                mode: Mode::Autonomous,
+                nav_status: None
             })))
         );
         assert!(iter.next().is_none());
     }
 }
+
+fn with_checksum(payload: &str) -> String {
+    let checksum = payload.bytes().fold(0u8, |acc, b| acc ^ b);
+    format!("${}*{:02X}\r\n", payload, checksum)
+}
+
+#[test]
+fn test_fix_stats() {
+    let mut p = Parser::new();
+    let mut stats = FixStats::new();
+    for second in 0..5 {
+        let gll = with_checksum(&format!(
+            "GPGLL,4916.45,N,12311.12,W,2359{:02},A",
+            55 + second
+        ));
+        for result in p.parse_from_bytes(gll.as_bytes()) {
+            stats.update(&result.unwrap());
+        }
+    }
+    assert!((stats.rate_hz().unwrap() - 1.0).abs() < 0.001);
+    assert_eq!(stats.last_gap_seconds(), Some(1.0));
+    // Midnight rollover with 3 seconds gap and no valid fix
+    let gll = with_checksum("GPGLL,4916.45,N,12311.12,W,000002,V");
+    for result in p.parse_from_bytes(gll.as_bytes()) {
+        stats.update(&result.unwrap());
+    }
+    assert_eq!(stats.last_gap_seconds(), Some(3.0));
+    assert_eq!(stats.min_gap_seconds(), Some(1.0));
+    assert_eq!(stats.max_gap_seconds(), Some(3.0));
+    assert_eq!(stats.epochs(), 6);
+    assert_eq!(stats.no_fix_epochs(), 1);
+}
+
+#[test]
+fn test_latest_cache() {
+    let mut p = Parser::new();
+    let mut cache = LatestCache::new();
+    let b = b"$GPRMC,125504.049,A,5542.2389,N,03741.6063,E,0.06,25.82,200906,,,A*56\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n\
+$GPVTG,089.0,T,,,15.2,N,,,A*12\r\n\
+$GPGGA,145700.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*6F\r\n\
+$GPGGA,,,,,,,,,,,,,,*56\r\n";
+    for result in p.parse_from_bytes(&b[..]) {
+        cache.update(&result.unwrap());
+    }
+    assert_eq!(cache.rmc().unwrap().datetime.time.hours, 12);
+    assert_eq!(cache.gga().unwrap().time.minutes, 57);
+    assert_eq!(cache.vtg().unwrap().speed, coords::Speed::from_knots(15.2));
+    assert!(cache.gll().is_none());
+    assert!(cache.gsa().is_none());
+    cache.clear();
+    assert!(cache.rmc().is_none());
+}
+
+#[test]
+fn test_cross_check_fix() {
+    let mut p = Parser::new();
+    let b = b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n\
+$GPGSA,A,3,21,5,29,25,12,10,26,,,,,,1.2,0.7,1.0*0B\r\n\
+$GPGSA,A,2,21,5,29,25,12,10,26,,,,,,1.2,0.7,1.0*0A\r\n";
+    let mut iter = p.parse_from_bytes(&b[..]);
+    let gga = match iter.next() {
+        Some(Ok(ParseResult::GGA(Some(gga)))) => gga,
+        _ => panic!("Unexpected ParseResult variant while parsing GGA data."),
+    };
+    let gsa_3d = match iter.next() {
+        Some(Ok(ParseResult::GSA(Some(gsa)))) => gsa,
+        _ => panic!("Unexpected ParseResult variant while parsing GSA data."),
+    };
+    let gsa_2d = match iter.next() {
+        Some(Ok(ParseResult::GSA(Some(gsa)))) => gsa,
+        _ => panic!("Unexpected ParseResult variant while parsing GSA data."),
+    };
+    assert_eq!(cross_check_fix(&gga, &gsa_3d), Ok(()));
+    assert_eq!(
+        cross_check_fix(&gga, &gsa_2d),
+        Err("GGA altitude is reported for 2D fix!")
+    );
+    let no_fix = GGA {
+        gps_quality: GPSQuality::NoFix,
+        ..gga.clone()
+    };
+    assert_eq!(
+        cross_check_fix(&no_fix, &gsa_3d),
+        Err("GGA coordinates are reported without fix!")
+    );
+    let few_satellites = GGA {
+        sat_in_use: 4,
+        ..gga
+    };
+    assert_eq!(
+        cross_check_fix(&few_satellites, &gsa_3d),
+        Err("GSA reports more satellites than GGA!")
+    );
+}
+
+#[test]
+fn test_fix_aggregator() {
+    let mut p = Parser::new();
+    let mut aggregator = FixAggregator::new(5.0);
+    let burst = b"$GPRMC,145659.00,A,5956.695396,N,03022.454999,E,0.06,25.82,200906,,,A*64\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n\
+$GNGSA,A,3,21,5,29,25,12,10,26,2,,,,,1.2,0.7,1.0*27\r\n";
+    assert!(aggregator.current().is_none());
+    for result in p.parse_from_bytes(&burst[..]) {
+        aggregator.update(&result.unwrap());
+    }
+    let fix = aggregator.current().unwrap();
+    assert_eq!(
+        fix.time,
+        datetime::Time {
+            hours: 14,
+            minutes: 56,
+            seconds: 59.0
+        }
+    );
+    assert_eq!(
+        fix.date,
+        Some(datetime::Date {
+            day: 20,
+            month: 9,
+            year: 2006
+        })
+    );
+    assert_eq!(fix.latitude, TryFrom::try_from(59.944923266667).unwrap());
+    assert_eq!(fix.longitude, TryFrom::try_from(30.3742499833).unwrap());
+    assert_eq!(fix.altitude, Some(coords::Altitude { meters: 9.0 }));
+    assert_eq!(fix.speed, Some(coords::Speed::from_knots(0.06)));
+    assert_eq!(fix.course, Some(From::from(25.82)));
+    assert_eq!(fix.gps_quality, Some(GPSQuality::DGPS));
+    assert_eq!(fix.sat_in_use, Some(7));
+    assert_eq!(fix.fix_type, Some(FixType::Fix3D));
+    assert_eq!(fix.pdop, Some(1.2));
+    assert_eq!(fix.hdop, Some(0.7));
+    assert_eq!(fix.vdop, Some(1.0));
+
+    // Next epoch carries GSA data but not GGA data
+    let next = b"$GPRMC,145700.00,A,5956.695400,N,03022.455000,E,0.10,26.00,200906,,,A*67\r\n";
+    for result in p.parse_from_bytes(&next[..]) {
+        aggregator.update(&result.unwrap());
+    }
+    let fix = aggregator.current().unwrap();
+    assert_eq!(fix.time.seconds, 0.0);
+    assert_eq!(fix.speed, Some(coords::Speed::from_knots(0.1)));
+    assert_eq!(fix.altitude, None);
+    assert_eq!(fix.gps_quality, None);
+    assert_eq!(fix.fix_type, Some(FixType::Fix3D));
+    assert_eq!(fix.pdop, Some(1.2));
+
+    // Stale GSA data is dropped after the gap
+    let stale = b"$GPRMC,145710.00,A,5956.695400,N,03022.455000,E,0.10,26.00,200906,,,A*66\r\n";
+    for result in p.parse_from_bytes(&stale[..]) {
+        aggregator.update(&result.unwrap());
+    }
+    let fix = aggregator.current().unwrap();
+    assert_eq!(fix.time.seconds, 10.0);
+    assert_eq!(fix.fix_type, None);
+    assert_eq!(fix.pdop, None);
+}
+
+#[test]
+fn test_fix_compact_round_trip() {
+    let mut p = Parser::new();
+    let mut aggregator = FixAggregator::new(5.0);
+    let burst = b"$GPRMC,145659.00,A,5956.695396,N,03022.454999,E,0.06,25.82,200906,,,A*64\r\n\
+$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n";
+    for result in p.parse_from_bytes(&burst[..]) {
+        aggregator.update(&result.unwrap());
+    }
+    let fix = aggregator.current().unwrap();
+    let compact = fix.to_compact();
+    assert_eq!(compact.len(), nmea0183::COMPACT_FIX_SIZE);
+    let decoded = Fix::from_compact(&compact).unwrap();
+    assert!((decoded.latitude.as_f64() - fix.latitude.as_f64()).abs() < 1e-7);
+    assert!((decoded.longitude.as_f64() - fix.longitude.as_f64()).abs() < 1e-7);
+    assert!(
+        (decoded.speed.as_ref().unwrap().as_cmps() - fix.speed.as_ref().unwrap().as_cmps()).abs()
+            < 1.0
+    );
+    assert!((decoded.course.as_ref().unwrap().degrees - 25.82).abs() < 0.01);
+    assert!((decoded.time.as_seconds_of_day() - fix.time.as_seconds_of_day()).abs() < 0.001);
+    assert_eq!(decoded.date, fix.date);
+    assert_eq!(decoded.gps_quality, Some(GPSQuality::DGPS));
+    assert_eq!(decoded.altitude, None);
+
+    let mut southwest = decoded.clone();
+    southwest.latitude = TryFrom::try_from(-33.8688).unwrap();
+    southwest.longitude = TryFrom::try_from(-151.2093).unwrap();
+    southwest.speed = None;
+    southwest.course = None;
+    southwest.date = None;
+    southwest.gps_quality = None;
+    let decoded = Fix::from_compact(&southwest.to_compact()).unwrap();
+    assert!((decoded.latitude.as_f64() + 33.8688).abs() < 1e-7);
+    assert!((decoded.longitude.as_f64() + 151.2093).abs() < 1e-7);
+    assert_eq!(decoded.speed, None);
+    assert_eq!(decoded.course, None);
+    assert_eq!(decoded.date, None);
+    assert_eq!(decoded.gps_quality, None);
+
+    let mut corrupted = compact;
+    corrupted[10..12].copy_from_slice(&36000u16.to_le_bytes());
+    assert!(Fix::from_compact(&corrupted).is_err());
+    let mut corrupted = compact;
+    corrupted[17] = 13;
+    assert_eq!(
+        Fix::from_compact(&corrupted),
+        Err("Months is not in range 1-12")
+    );
+    let mut corrupted = compact;
+    corrupted[16] = 32;
+    assert_eq!(
+        Fix::from_compact(&corrupted),
+        Err("Day is not in range 1-31")
+    );
+
+    let mut old = fix.clone();
+    old.date = Some(datetime::Date {
+        day: 31,
+        month: 12,
+        year: 1999,
+    });
+    let decoded = Fix::from_compact(&old.to_compact()).unwrap();
+    assert_eq!(decoded.date, old.date);
+    old.date = Some(datetime::Date {
+        day: 1,
+        month: 1,
+        year: 2256,
+    });
+    assert_eq!(Fix::from_compact(&old.to_compact()).unwrap().date, None);
+}